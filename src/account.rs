@@ -143,10 +143,10 @@ impl TryFrom<ProgramAccount> for Account {
             return Err(JsValue::from("Invalid ProgramAccount").into());
         }
         Ok(Self {
-            lamports: account.lamports().as_f64().unwrap() as u64,
+            lamports: u64_from_jsvalue(account.lamports().as_ref(), "lamports")?,
             data: account.data(),
             owner: account.owner().try_into()?,
-            rent_epoch: account.rent_epoch().as_f64().unwrap() as u64,
+            rent_epoch: rent_epoch_from_jsvalue(account.rent_epoch().as_ref())?,
             executable: account.executable(),
         })
     }
@@ -166,3 +166,52 @@ impl TryFrom<Account> for ProgramAccount {
         Ok(acc)
     }
 }
+
+/// `rentEpoch` of rent-exempt accounts is `u64::MAX`, which reaches JavaScript
+/// as the `number` 2^64 once JSON-decoded. web3.js leaves `rentEpoch` out
+/// when the RPC node does not report it, in which case it defaults to `0`.
+pub(crate) fn rent_epoch_from_jsvalue(value: &JsValue) -> Result<u64> {
+    if value.is_bigint() {
+        return u64_from_jsvalue(value, "rentEpoch");
+    }
+    if value.is_undefined() || value.is_null() {
+        return rent_epoch_from_number(None);
+    }
+    match value.as_f64() {
+        Some(number) => rent_epoch_from_number(Some(number)),
+        None => Err(crate::error::Error::InvalidField("rentEpoch".to_string())),
+    }
+}
+
+fn rent_epoch_from_number(number: Option<f64>) -> Result<u64> {
+    match number {
+        None => Ok(0),
+        Some(number) if number == u64::MAX as f64 => Ok(u64::MAX),
+        Some(number) => u64_from_f64(number, "rentEpoch"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rent_epoch_of_rent_exempt_accounts_is_u64_max() {
+        assert_eq!(
+            rent_epoch_from_number(Some(2f64.powi(64))).unwrap(),
+            u64::MAX
+        );
+    }
+
+    #[test]
+    fn missing_rent_epoch_defaults_to_zero() {
+        assert_eq!(rent_epoch_from_number(None).unwrap(), 0);
+    }
+
+    #[test]
+    fn rent_epoch_numbers_must_be_exact() {
+        assert_eq!(rent_epoch_from_number(Some(361.0)).unwrap(), 361);
+        assert!(rent_epoch_from_number(Some(2f64.powi(63))).is_err());
+        assert!(rent_epoch_from_number(Some(-1.0)).is_err());
+    }
+}
@@ -2,13 +2,18 @@
 //! Misc. bindings for utility classes (`RpcProgramAccountsConfig` etc.)
 //!
 
+use crate::error::Error;
 use crate::imports::*;
 use js_sys::Reflect;
 //use workflow_log::log_trace;
-use crate::account::ProgramAccount;
+use crate::account::{rent_epoch_from_jsvalue, ProgramAccount};
 use crate::publickey::PublicKey;
-use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::account::Account;
+use solana_sdk::commitment_config::{CommitmentConfig, CommitmentLevel};
+use solana_sdk::hash::Hash;
+use solana_sdk::signature::Signature;
 use solana_sdk::slot_history::Slot;
+use solana_sdk::transaction_context::TransactionReturnData;
 
 #[wasm_bindgen]
 extern "C" {
@@ -26,13 +31,28 @@ extern "C" {
     #[wasm_bindgen(getter, method, js_name = "pubkey")]
     pub fn pubkey_impl(this: &ProgramAccountsResultItem) -> PublicKey;
 
+    #[wasm_bindgen(extends = Object)]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    /// BlockheightBasedTransactionConfirmationStrategy
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/types/BlockheightBasedTransactionConfirmationStrategy.html)
+    ///
+    pub type BlockheightBasedTransactionConfirmationStrategy;
+
+    #[wasm_bindgen(extends = Object)]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    /// SimulateTransactionConfig
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/types/SimulateTransactionConfig.html)
+    ///
+    pub type SimulateTransactionConfig;
 }
 
 impl OptionsTrait for RpcProgramAccountsConfig {}
 
 impl RpcProgramAccountsConfig {
-    pub fn add_filters(self, filters: Array) -> Result<Self> {
-        Ok(self.set("filters", filters.into()))
+    pub fn add_filters(self, filters: Vec<RpcFilterType>) -> Result<Self> {
+        Ok(self.set("filters", filters_to_jsvalue(&filters)?))
     }
 
     pub fn encoding(self, encoding: RpcAccountEncoding) -> Result<Self> {
@@ -52,6 +72,314 @@ impl RpcProgramAccountsConfig {
     }
 }
 
+/// Maximum size of the decoded `memcmp` bytes accepted by RPC nodes
+pub const MAX_MEMCMP_DATA_SIZE: usize = 128;
+
+/// `getProgramAccounts` filter
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RpcFilterType {
+    /// Match accounts with the given data length
+    DataSize(u64),
+    /// Match accounts whose data contains the given bytes at an offset
+    Memcmp(Memcmp),
+    /// Match SPL Token accounts that are in a valid state
+    TokenAccountState,
+}
+
+/// Bytes compared by a [`Memcmp`] filter
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MemcmpEncodedBytes {
+    Base58(String),
+    Base64(String),
+    Bytes(Vec<u8>),
+}
+
+/// `memcmp` filter comparing account data at `offset`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Memcmp {
+    pub offset: usize,
+    pub bytes: MemcmpEncodedBytes,
+}
+
+impl Memcmp {
+    pub fn new(offset: usize, bytes: MemcmpEncodedBytes) -> Self {
+        Self { offset, bytes }
+    }
+
+    pub fn new_raw_bytes(offset: usize, bytes: Vec<u8>) -> Self {
+        Self::new(offset, MemcmpEncodedBytes::Bytes(bytes))
+    }
+
+    pub fn new_base58_encoded(offset: usize, bytes: &[u8]) -> Self {
+        Self::new(
+            offset,
+            MemcmpEncodedBytes::Base58(bs58::encode(bytes).into_string()),
+        )
+    }
+
+    /// Decoded bytes compared by this filter
+    pub fn bytes(&self) -> Result<Vec<u8>> {
+        match &self.bytes {
+            MemcmpEncodedBytes::Base58(bytes) => bs58::decode(bytes)
+                .into_vec()
+                .map_err(|err| Error::InvalidFilter(format!("memcmp base58: {err}"))),
+            MemcmpEncodedBytes::Base64(bytes) => base64::decode(bytes)
+                .map_err(|err| Error::InvalidFilter(format!("memcmp base64: {err}"))),
+            MemcmpEncodedBytes::Bytes(bytes) => Ok(bytes.clone()),
+        }
+    }
+}
+
+impl RpcFilterType {
+    /// Check that the filter will be accepted by RPC nodes
+    pub fn verify(&self) -> Result<()> {
+        match self {
+            RpcFilterType::DataSize(_) | RpcFilterType::TokenAccountState => Ok(()),
+            RpcFilterType::Memcmp(memcmp) => {
+                let size = memcmp.bytes()?.len();
+                if size > MAX_MEMCMP_DATA_SIZE {
+                    Err(Error::InvalidFilter(format!(
+                        "memcmp data size {size} exceeds {MAX_MEMCMP_DATA_SIZE} bytes"
+                    )))
+                } else {
+                    Ok(())
+                }
+            }
+        }
+    }
+}
+
+impl TryFrom<&RpcFilterType> for JsValue {
+    type Error = Error;
+
+    fn try_from(filter: &RpcFilterType) -> Result<Self> {
+        filter.verify()?;
+        match filter {
+            RpcFilterType::DataSize(size) => {
+                let obj = Object::new();
+                Reflect::set(&obj, &"dataSize".into(), &JsValue::from(*size as f64))?;
+                Ok(obj.into())
+            }
+            RpcFilterType::Memcmp(memcmp) => {
+                let (bytes, encoding) = match &memcmp.bytes {
+                    MemcmpEncodedBytes::Base58(bytes) => (bytes.clone(), "base58"),
+                    MemcmpEncodedBytes::Base64(bytes) => (bytes.clone(), "base64"),
+                    MemcmpEncodedBytes::Bytes(bytes) => (base64::encode(bytes), "base64"),
+                };
+                let inner = Object::new();
+                Reflect::set(&inner, &"offset".into(), &JsValue::from(memcmp.offset))?;
+                Reflect::set(&inner, &"bytes".into(), &JsValue::from(bytes))?;
+                Reflect::set(&inner, &"encoding".into(), &JsValue::from(encoding))?;
+                let obj = Object::new();
+                Reflect::set(&obj, &"memcmp".into(), &inner)?;
+                Ok(obj.into())
+            }
+            RpcFilterType::TokenAccountState => Ok("tokenAccountState".into()),
+        }
+    }
+}
+
+/// Convert a list of [`RpcFilterType`] into a JavaScript array
+pub fn filters_to_jsvalue(filters: &[RpcFilterType]) -> Result<JsValue> {
+    let list = Array::new();
+    for filter in filters {
+        list.push(&JsValue::try_from(filter)?);
+    }
+    Ok(list.into())
+}
+
+impl OptionsTrait for BlockheightBasedTransactionConfirmationStrategy {}
+
+impl BlockheightBasedTransactionConfirmationStrategy {
+    /// Set signature
+    pub fn signature(self, signature: &Signature) -> Self {
+        self.set("signature", signature.to_string().into())
+    }
+
+    /// Set blockhash
+    pub fn blockhash(self, blockhash: &Hash) -> Self {
+        self.set("blockhash", blockhash.to_string().into())
+    }
+
+    /// Set lastValidBlockHeight
+    pub fn last_valid_block_height(self, last_valid_block_height: u64) -> Self {
+        self.set(
+            "lastValidBlockHeight",
+            JsValue::from(last_valid_block_height as f64),
+        )
+    }
+}
+
+/// Outcome of [`Connection::send_and_confirm`](crate::connection::Connection::send_and_confirm)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransactionConfirmation {
+    /// Transaction reached the requested commitment
+    Confirmed {
+        signature: Signature,
+        slot: Slot,
+        commitment: CommitmentLevel,
+    },
+    /// Transaction was processed but failed
+    Failed {
+        signature: Signature,
+        slot: Slot,
+        err: TransactionStatusError,
+    },
+    /// Blockhash expired before the transaction was confirmed
+    Expired { signature: Signature },
+}
+
+impl TransactionConfirmation {
+    pub fn signature(&self) -> &Signature {
+        match self {
+            TransactionConfirmation::Confirmed { signature, .. }
+            | TransactionConfirmation::Failed { signature, .. }
+            | TransactionConfirmation::Expired { signature } => signature,
+        }
+    }
+
+    pub fn is_confirmed(&self) -> bool {
+        matches!(self, TransactionConfirmation::Confirmed { .. })
+    }
+}
+
+impl OptionsTrait for SimulateTransactionConfig {}
+
+impl SimulateTransactionConfig {
+    /// Set sigVerify
+    pub fn sig_verify(self, sig_verify: bool) -> Self {
+        self.set("sigVerify", JsValue::from(sig_verify))
+    }
+
+    /// Set replaceRecentBlockhash
+    pub fn replace_recent_blockhash(self, replace_recent_blockhash: bool) -> Self {
+        self.set(
+            "replaceRecentBlockhash",
+            JsValue::from(replace_recent_blockhash),
+        )
+    }
+
+    /// Set commitment
+    pub fn commitment(self, commitment: CommitmentConfig) -> Self {
+        self.set("commitment", commitment.commitment.to_string().into())
+    }
+
+    /// Set accounts whose post-simulation state should be returned
+    pub fn accounts(self, addresses: &[Pubkey]) -> Result<Self> {
+        let accounts = Object::new();
+        let list = addresses
+            .iter()
+            .map(|address| JsValue::from(address.to_string()))
+            .collect::<Array>();
+        Reflect::set(
+            &accounts,
+            &JsValue::from("encoding"),
+            &JsValue::from("base64"),
+        )?;
+        Reflect::set(&accounts, &JsValue::from("addresses"), &list)?;
+        Ok(self.set("accounts", accounts.into()))
+    }
+}
+
+/// Result of [`Connection::simulate_transaction`](crate::connection::Connection::simulate_transaction)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SimulateTransactionResult {
+    /// Error the transaction failed with, `None` on success
+    pub err: Option<TransactionStatusError>,
+    /// Program log messages
+    pub logs: Vec<String>,
+    /// Compute units consumed by the transaction
+    pub units_consumed: Option<u64>,
+    /// Data returned by the last program that called `set_return_data`
+    pub return_data: Option<TransactionReturnData>,
+    /// State of the accounts requested through [`SimulateTransactionConfig::accounts`],
+    /// in the same order; `None` for accounts that do not exist
+    pub accounts: Vec<Option<Account>>,
+}
+
+impl TryFrom<&JsValue> for SimulateTransactionResult {
+    type Error = crate::error::Error;
+
+    fn try_from(value: &JsValue) -> Result<Self> {
+        let get = |name: &str| Reflect::get(value, &JsValue::from(name));
+
+        let logs = get("logs")?;
+        let logs = if logs.is_array() {
+            Array::from(&logs)
+                .iter()
+                .filter_map(|log| log.as_string())
+                .collect()
+        } else {
+            vec![]
+        };
+
+        let units_consumed = get("unitsConsumed")?;
+        let units_consumed = if units_consumed.is_null() || units_consumed.is_undefined() {
+            None
+        } else {
+            Some(u64_from_jsvalue(&units_consumed, "unitsConsumed")?)
+        };
+
+        let return_data = get("returnData")?;
+        let return_data = if return_data.is_object() {
+            let program_id = Reflect::get(&return_data, &JsValue::from("programId"))?
+                .as_string()
+                .ok_or_else(|| Error::InvalidField("returnData.programId".to_string()))?
+                .parse()?;
+            let data = Reflect::get(&return_data, &JsValue::from("data"))?;
+            let data = base64_field(&Array::from(&data).get(0), "returnData.data")?;
+            Some(TransactionReturnData { program_id, data })
+        } else {
+            None
+        };
+
+        let accounts = get("accounts")?;
+        let accounts = if accounts.is_array() {
+            Array::from(&accounts)
+                .iter()
+                .map(|account| account_from_rpc_jsvalue(&account))
+                .collect::<Result<Vec<_>>>()?
+        } else {
+            vec![]
+        };
+
+        Ok(Self {
+            err: transaction_error_from_jsvalue(&get("err")?)?,
+            logs,
+            units_consumed,
+            return_data,
+            accounts,
+        })
+    }
+}
+
+fn base64_field(value: &JsValue, field: &str) -> Result<Vec<u8>> {
+    let value = value
+        .as_string()
+        .ok_or_else(|| Error::InvalidField(field.to_string()))?;
+    base64::decode(value).map_err(|_| Error::InvalidField(field.to_string()))
+}
+
+/// Decode a base64-encoded account as returned by the JSON RPC API
+fn account_from_rpc_jsvalue(value: &JsValue) -> Result<Option<Account>> {
+    if !value.is_object() {
+        return Ok(None);
+    }
+    let get = |name: &str| Reflect::get(value, &JsValue::from(name));
+    let owner = get("owner")?
+        .as_string()
+        .ok_or_else(|| Error::InvalidField("owner".to_string()))?
+        .parse()?;
+    let data = base64_field(&Array::from(&get("data")?).get(0), "data")?;
+    Ok(Some(Account {
+        lamports: u64_from_jsvalue(&get("lamports")?, "lamports")?,
+        data,
+        owner,
+        executable: get("executable")?.as_bool().unwrap_or(false),
+        rent_epoch: rent_epoch_from_jsvalue(&get("rentEpoch")?)?,
+    }))
+}
+
 pub enum RpcAccountEncoding {
     Base58,
     Base64,
@@ -92,3 +420,70 @@ impl ProgramAccountsResultItem {
         self.pubkey_impl().try_into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn memcmp(bytes: MemcmpEncodedBytes) -> RpcFilterType {
+        RpcFilterType::Memcmp(Memcmp::new(0, bytes))
+    }
+
+    #[test]
+    fn memcmp_size_limit() {
+        let max = vec![1; MAX_MEMCMP_DATA_SIZE];
+        let over = vec![1; MAX_MEMCMP_DATA_SIZE + 1];
+        assert!(memcmp(MemcmpEncodedBytes::Bytes(max.clone()))
+            .verify()
+            .is_ok());
+        assert!(RpcFilterType::Memcmp(Memcmp::new_base58_encoded(0, &max))
+            .verify()
+            .is_ok());
+        assert!(memcmp(MemcmpEncodedBytes::Base64(base64::encode(&max)))
+            .verify()
+            .is_ok());
+
+        for filter in [
+            memcmp(MemcmpEncodedBytes::Bytes(over.clone())),
+            RpcFilterType::Memcmp(Memcmp::new_base58_encoded(0, &over)),
+            memcmp(MemcmpEncodedBytes::Base64(base64::encode(&over))),
+        ] {
+            assert!(matches!(filter.verify(), Err(Error::InvalidFilter(_))));
+        }
+        assert!(RpcFilterType::DataSize(u64::MAX).verify().is_ok());
+        assert!(RpcFilterType::TokenAccountState.verify().is_ok());
+    }
+
+    #[test]
+    fn memcmp_rejects_invalid_encodings() {
+        let base58 = Memcmp::new(0, MemcmpEncodedBytes::Base58("0OIl".to_string()));
+        assert!(matches!(base58.bytes(), Err(Error::InvalidFilter(_))));
+        let base64 = Memcmp::new(0, MemcmpEncodedBytes::Base64("not base64!".to_string()));
+        assert!(matches!(base64.bytes(), Err(Error::InvalidFilter(_))));
+        for memcmp in [base58, base64] {
+            assert!(matches!(
+                RpcFilterType::Memcmp(memcmp).verify(),
+                Err(Error::InvalidFilter(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn memcmp_decodes_bytes() {
+        let bytes = vec![0, 1, 2, 255];
+        assert_eq!(
+            Memcmp::new_base58_encoded(4, &bytes).bytes().unwrap(),
+            bytes
+        );
+        assert_eq!(
+            Memcmp::new(4, MemcmpEncodedBytes::Base64(base64::encode(&bytes)))
+                .bytes()
+                .unwrap(),
+            bytes
+        );
+        assert_eq!(
+            Memcmp::new_raw_bytes(4, bytes.clone()).bytes().unwrap(),
+            bytes
+        );
+    }
+}
@@ -0,0 +1,151 @@
+//!
+//! [`ComputeBudgetProgram`](https://solana-labs.github.io/solana-web3.js/classes/ComputeBudgetProgram.html) class bindings
+//! and priority fee settings for [`Transaction::set_priority_fee`](crate::transaction::Transaction::set_priority_fee).
+//!
+
+use crate::imports::*;
+use crate::instruction::TransactionInstruction;
+use js_sys::{BigInt, Reflect};
+
+/// Maximum compute unit limit a transaction can request
+pub const MAX_COMPUTE_UNIT_LIMIT: u32 = 1_400_000;
+
+/// Maximum number of accounts accepted by `getRecentPrioritizationFees`
+pub(crate) const MAX_PRIORITIZATION_FEE_ACCOUNTS: usize = 128;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(extends = Object)]
+    #[derive(Debug, Clone)]
+    /// ComputeBudgetProgram
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/ComputeBudgetProgram.html)
+    ///
+    pub type ComputeBudgetProgram;
+}
+
+impl ComputeBudgetProgram {
+    /// Generate an instruction that sets the compute unit limit of the transaction
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/ComputeBudgetProgram.html#setComputeUnitLimit)
+    ///
+    pub fn set_compute_unit_limit(units: u32) -> Result<TransactionInstruction> {
+        let params = Object::new();
+        Reflect::set(&params, &JsValue::from("units"), &JsValue::from(units))?;
+        web3_call_static("ComputeBudgetProgram", "setComputeUnitLimit", &[&params])
+    }
+
+    /// Generate an instruction that sets the compute unit price in micro-lamports
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/ComputeBudgetProgram.html#setComputeUnitPrice)
+    ///
+    pub fn set_compute_unit_price(micro_lamports: u64) -> Result<TransactionInstruction> {
+        let params = Object::new();
+        Reflect::set(
+            &params,
+            &JsValue::from("microLamports"),
+            &BigInt::from(micro_lamports).into(),
+        )?;
+        web3_call_static("ComputeBudgetProgram", "setComputeUnitPrice", &[&params])
+    }
+}
+
+/// Settings used by [`Transaction::set_priority_fee`](crate::transaction::Transaction::set_priority_fee)
+#[derive(Debug, Clone, PartialEq)]
+pub struct PriorityFeeConfig {
+    /// Fraction of the simulated compute units added as a safety margin
+    pub margin: f64,
+    /// Percentile (0-100) of recent prioritization fees used as the price
+    pub percentile: u8,
+    /// Upper bound for the compute unit price, in micro-lamports
+    pub max_micro_lamports: Option<u64>,
+}
+
+impl Default for PriorityFeeConfig {
+    fn default() -> Self {
+        Self {
+            margin: 0.1,
+            percentile: 75,
+            max_micro_lamports: None,
+        }
+    }
+}
+
+impl PriorityFeeConfig {
+    pub fn with_margin(mut self, margin: f64) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    pub fn with_percentile(mut self, percentile: u8) -> Self {
+        self.percentile = percentile.min(100);
+        self
+    }
+
+    pub fn with_max_micro_lamports(mut self, max_micro_lamports: u64) -> Self {
+        self.max_micro_lamports = Some(max_micro_lamports);
+        self
+    }
+}
+
+/// Compute budget applied by [`Transaction::set_priority_fee`](crate::transaction::Transaction::set_priority_fee)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PriorityFee {
+    /// Compute units consumed during simulation
+    pub units_consumed: u64,
+    /// Requested compute unit limit, including the safety margin
+    pub compute_unit_limit: u32,
+    /// Requested compute unit price, in micro-lamports
+    pub micro_lamports: u64,
+}
+
+pub(crate) fn instructions_to_array(
+    compute_budget: &[TransactionInstruction],
+    instructions: &[TransactionInstruction],
+) -> Array {
+    compute_budget
+        .iter()
+        .chain(instructions)
+        .map(JsValue::from)
+        .collect()
+}
+
+/// Nearest-rank percentile of `fees`, `0` when there are no samples
+pub(crate) fn fee_percentile(mut fees: Vec<u64>, percentile: u8) -> u64 {
+    if fees.is_empty() {
+        return 0;
+    }
+    fees.sort_unstable();
+    let rank = (percentile.min(100) as usize * fees.len()).div_ceil(100);
+    fees[rank.saturating_sub(1)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fee_percentile_of_no_samples_is_zero() {
+        assert_eq!(fee_percentile(vec![], 0), 0);
+        assert_eq!(fee_percentile(vec![], 75), 0);
+    }
+
+    #[test]
+    fn fee_percentile_of_single_sample() {
+        for percentile in [0, 50, 100] {
+            assert_eq!(fee_percentile(vec![42], percentile), 42);
+        }
+    }
+
+    #[test]
+    fn fee_percentile_uses_nearest_rank() {
+        let fees = vec![50, 10, 40, 20, 30];
+        assert_eq!(fee_percentile(fees.clone(), 0), 10);
+        assert_eq!(fee_percentile(fees.clone(), 20), 10);
+        assert_eq!(fee_percentile(fees.clone(), 21), 20);
+        assert_eq!(fee_percentile(fees.clone(), 50), 30);
+        assert_eq!(fee_percentile(fees.clone(), 75), 40);
+        assert_eq!(fee_percentile(fees.clone(), 100), 50);
+        assert_eq!(fee_percentile(fees, 255), 50);
+    }
+}
@@ -3,30 +3,43 @@
 //!
 use crate::account::ProgramAccount;
 use crate::api::*;
+use crate::error::Error;
+use crate::history::*;
 use crate::imports::*;
+use crate::message::{AddressLookupTableAccount, MessageV0};
+use crate::nonce::{nonce_data_from_account_data, NonceAccount, NONCE_ACCOUNT_LENGTH};
 use crate::publickey::PublicKey;
+use crate::subscription::*;
+use crate::system_program::SystemProgram;
+use crate::transaction::{SerializeConfig, Transaction};
+use crate::versioned_transaction::VersionedTransaction;
+use futures::stream::{self, StreamExt, TryStreamExt};
+use js_sys::{Function, Reflect};
 use solana_sdk::account::Account;
+use solana_sdk::commitment_config::{CommitmentConfig, CommitmentLevel};
+use solana_sdk::hash::Hash;
+use solana_sdk::signature::Signature;
+use solana_sdk::slot_history::Slot;
 //use workflow_log::log_trace;
 
+/// Maximum number of accounts the RPC accepts in a single `getMultipleAccounts` request
+pub const MAX_MULTIPLE_ACCOUNTS: usize = 100;
+
+/// Number of `getMultipleAccounts` requests [`Connection::get_multiple_accounts`] keeps in flight
+pub const DEFAULT_MULTIPLE_ACCOUNTS_CONCURRENCY: usize = 4;
+
 #[wasm_bindgen]
 extern "C" {
-    #[wasm_bindgen(js_namespace=solanaWeb3, js_name = Connection)]
+    #[wasm_bindgen(extends = Object)]
     #[derive(Debug, Clone)]
     pub type Connection;
 
-    #[wasm_bindgen(constructor, js_namespace=["solanaWeb3"])]
-    /// Create Connection
-    ///
-    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Connection.html)
-    ///
-    pub fn new(endpoint: String) -> Connection;
-
-    #[wasm_bindgen(constructor, js_namespace=["solanaWeb3"])]
-    /// Create Connection
+    #[wasm_bindgen(getter, method, js_name = "commitment")]
+    /// The default commitment used for requests
     ///
-    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Connection.html)
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Connection.html#commitment)
     ///
-    pub fn new_with_commitment(endpoint: String, commitment: String) -> Connection;
+    pub fn commitment_impl(this: &Connection) -> Option<String>;
 
     #[wasm_bindgen(method, catch, js_name = "getLatestBlockhash")]
     /// Fetch the latest blockhash from the cluster
@@ -40,9 +53,19 @@ extern "C" {
     ///
     /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Connection.html#getLatestBlockhash)
     ///
-    pub async fn get_latest_block_hash_with_commitment(
+    pub async fn get_latest_block_hash_with_commitment_impl(
         this: &Connection,
-        commitment: String,
+        commitment: JsValue,
+    ) -> Result<JsValue>;
+
+    #[wasm_bindgen(method, catch, js_name = "getLatestBlockhashAndContext")]
+    /// Fetch the latest blockhash from the cluster along with the slot it was observed at
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Connection.html#getLatestBlockhashAndContext)
+    ///
+    pub async fn get_latest_block_hash_and_context_impl(
+        this: &Connection,
+        commitment: JsValue,
     ) -> Result<JsValue>;
 
     #[wasm_bindgen(method, catch, js_name = "sendRawTransaction")]
@@ -63,6 +86,38 @@ extern "C" {
         options: JsValue,
     ) -> Result<JsValue>;
 
+    #[wasm_bindgen(method, catch, js_name = "confirmTransaction")]
+    /// Confirm the transaction identified by the signature, failing once the blockhash expires
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Connection.html#confirmTransaction)
+    ///
+    pub async fn confirm_transaction_impl(
+        this: &Connection,
+        strategy: BlockheightBasedTransactionConfirmationStrategy,
+        commitment: JsValue,
+    ) -> Result<JsValue>;
+
+    #[wasm_bindgen(method, catch, js_name = "sendTransaction")]
+    /// Send a signed [`VersionedTransaction`]
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Connection.html#sendTransaction)
+    ///
+    pub async fn send_versioned_transaction_impl(
+        this: &Connection,
+        tx: VersionedTransaction,
+        options: JsValue,
+    ) -> Result<JsValue>;
+
+    #[wasm_bindgen(method, catch, js_name = "getAddressLookupTable")]
+    /// Fetch the address lookup table account for the specified public key
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Connection.html#getAddressLookupTable)
+    ///
+    pub async fn get_address_lookup_table_impl(
+        this: &Connection,
+        public_key: JsValue,
+    ) -> Result<JsValue>;
+
     #[wasm_bindgen(method, catch, js_name = "getAccountInfo")]
     /// Fetch all the account info for the specified public key
     ///
@@ -81,6 +136,93 @@ extern "C" {
         options: JsValue,
     ) -> Result<JsValue>;
 
+    #[wasm_bindgen(method, catch, js_name = "getMultipleAccountsInfo")]
+    /// Fetch all the account info for multiple accounts specified by an array of public keys
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Connection.html#getMultipleAccountsInfo)
+    ///
+    pub async fn get_multiple_accounts_info_impl(
+        this: &Connection,
+        public_keys: Array,
+    ) -> Result<JsValue>;
+
+    #[wasm_bindgen(method, catch, js_name = "getBalance")]
+    /// Fetch the balance for the specified public key
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Connection.html#getBalance)
+    ///
+    pub async fn get_balance_impl(
+        this: &Connection,
+        public_key: JsValue,
+        commitment: JsValue,
+    ) -> Result<JsValue>;
+
+    #[wasm_bindgen(method, catch, js_name = "getMinimumBalanceForRentExemption")]
+    /// Fetch the minimum balance needed to exempt an account of `dataLength` size from rent
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Connection.html#getMinimumBalanceForRentExemption)
+    ///
+    pub async fn get_minimum_balance_for_rent_exemption_impl(
+        this: &Connection,
+        data_length: f64,
+        commitment: JsValue,
+    ) -> Result<JsValue>;
+
+    #[wasm_bindgen(method, catch, js_name = "getFeeForMessage")]
+    /// Fetch the fee for a message
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Connection.html#getFeeForMessage)
+    ///
+    pub async fn get_fee_for_message_impl(
+        this: &Connection,
+        message: &JsValue,
+        commitment: JsValue,
+    ) -> Result<JsValue>;
+
+    #[wasm_bindgen(method, catch, js_name = "getRecentPrioritizationFees")]
+    /// Fetch a list of prioritization fees from recent blocks
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Connection.html#getRecentPrioritizationFees)
+    ///
+    pub async fn get_recent_prioritization_fees_impl(
+        this: &Connection,
+        config: JsValue,
+    ) -> Result<JsValue>;
+
+    #[wasm_bindgen(method, catch, js_name = "simulateTransaction")]
+    /// Simulate a transaction
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Connection.html#simulateTransaction)
+    ///
+    pub async fn simulate_transaction_impl(
+        this: &Connection,
+        tx: &VersionedTransaction,
+        config: JsValue,
+    ) -> Result<JsValue>;
+
+    #[wasm_bindgen(method, catch, js_name = "getSignaturesForAddress")]
+    /// Returns confirmed signatures for transactions involving an address backwards in time from the provided signature or most recent confirmed block
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Connection.html#getSignaturesForAddress)
+    ///
+    pub async fn get_signatures_for_address_impl(
+        this: &Connection,
+        address: JsValue,
+        options: JsValue,
+        commitment: JsValue,
+    ) -> Result<JsValue>;
+
+    #[wasm_bindgen(method, catch, js_name = "getTransaction")]
+    /// Fetch a confirmed or finalized transaction from the cluster
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Connection.html#getTransaction)
+    ///
+    pub async fn get_transaction_impl(
+        this: &Connection,
+        signature: String,
+        config: GetVersionedTransactionConfig,
+    ) -> Result<JsValue>;
+
     #[wasm_bindgen(method, catch, js_name = "getProgramAccounts")]
     /// Fetch all the account info for the specified public key
     ///
@@ -101,26 +243,275 @@ extern "C" {
     ///
     /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Connection.html#getLatestBlockhash)
     ///
-    pub fn block_hash(this: &LatestBlockhashInfo) -> JsValue;
+    pub fn block_hash_impl(this: &LatestBlockhashInfo) -> JsValue;
 
     #[wasm_bindgen(getter, method, js_name = "lastValidBlockHeight")]
     /// get lastValidBlockHeight
     ///
     /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Connection.html#getLatestBlockhash)
     ///
-    pub fn last_valid_block_height(this: &LatestBlockhashInfo) -> JsValue;
+    pub fn last_valid_block_height_impl(this: &LatestBlockhashInfo) -> JsValue;
 
     #[wasm_bindgen(extends = Object)]
     #[derive(Debug, Clone, PartialEq, Eq)]
     pub type SendRawTxOptions;
 
+    #[wasm_bindgen(extends = Object)]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    /// ConnectionConfig
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/types/ConnectionConfig.html)
+    ///
+    pub type ConnectionConfig;
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(method, catch, js_name = "onAccountChange")]
+    /// Register a callback to be invoked whenever the specified account changes
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Connection.html#onAccountChange)
+    ///
+    pub fn on_account_change_impl(
+        this: &Connection,
+        public_key: JsValue,
+        callback: &Function,
+        commitment: JsValue,
+    ) -> Result<u32>;
+
+    #[wasm_bindgen(method, catch, js_name = "removeAccountChangeListener")]
+    /// Deregister an account notification callback
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Connection.html#removeAccountChangeListener)
+    ///
+    pub async fn remove_account_change_listener(this: &Connection, id: u32) -> Result<JsValue>;
+
+    #[wasm_bindgen(method, catch, js_name = "onProgramAccountChange")]
+    /// Register a callback to be invoked whenever accounts owned by the specified program change
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Connection.html#onProgramAccountChange)
+    ///
+    pub fn on_program_account_change_impl(
+        this: &Connection,
+        program_id: JsValue,
+        callback: &Function,
+        commitment: JsValue,
+        filters: JsValue,
+    ) -> Result<u32>;
+
+    #[wasm_bindgen(method, catch, js_name = "removeProgramAccountChangeListener")]
+    /// Deregister a program account notification callback
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Connection.html#removeProgramAccountChangeListener)
+    ///
+    pub async fn remove_program_account_change_listener(
+        this: &Connection,
+        id: u32,
+    ) -> Result<JsValue>;
+
+    #[wasm_bindgen(method, catch, js_name = "onLogs")]
+    /// Register a callback to be invoked whenever logs are emitted
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Connection.html#onLogs)
+    ///
+    pub fn on_logs_impl(
+        this: &Connection,
+        filter: JsValue,
+        callback: &Function,
+        commitment: JsValue,
+    ) -> Result<u32>;
+
+    #[wasm_bindgen(method, catch, js_name = "removeOnLogsListener")]
+    /// Deregister a logs callback
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Connection.html#removeOnLogsListener)
+    ///
+    pub async fn remove_on_logs_listener(this: &Connection, id: u32) -> Result<JsValue>;
+
+    #[wasm_bindgen(method, catch, js_name = "onSlotChange")]
+    /// Register a callback to be invoked upon slot changes
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Connection.html#onSlotChange)
+    ///
+    pub fn on_slot_change_impl(this: &Connection, callback: &Function) -> Result<u32>;
+
+    #[wasm_bindgen(method, catch, js_name = "removeSlotChangeListener")]
+    /// Deregister a slot notification callback
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Connection.html#removeSlotChangeListener)
+    ///
+    pub async fn remove_slot_change_listener(this: &Connection, id: u32) -> Result<JsValue>;
+
+    #[wasm_bindgen(method, catch, js_name = "onSignature")]
+    /// Register a callback to be invoked upon signature updates
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Connection.html#onSignature)
+    ///
+    pub fn on_signature_impl(
+        this: &Connection,
+        signature: String,
+        callback: &Function,
+        commitment: JsValue,
+    ) -> Result<u32>;
+
+    #[wasm_bindgen(method, catch, js_name = "removeSignatureListener")]
+    /// Deregister a signature notification callback
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Connection.html#removeSignatureListener)
+    ///
+    pub async fn remove_signature_listener(this: &Connection, id: u32) -> Result<JsValue>;
+
+    #[wasm_bindgen(method, catch, js_name = "onRootChange")]
+    /// Register a callback to be invoked upon root changes
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Connection.html#onRootChange)
+    ///
+    pub fn on_root_change_impl(this: &Connection, callback: &Function) -> Result<u32>;
+
+    #[wasm_bindgen(method, catch, js_name = "removeRootChangeListener")]
+    /// Deregister a root notification callback
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Connection.html#removeRootChangeListener)
+    ///
+    pub async fn remove_root_change_listener(this: &Connection, id: u32) -> Result<JsValue>;
 }
 
 impl Connection {
+    /// Create Connection
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Connection.html)
+    ///
+    pub fn new(endpoint: String) -> Result<Connection> {
+        web3_construct("Connection", &[&JsValue::from(endpoint)])
+    }
+
+    /// Create Connection
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Connection.html)
+    ///
+    pub fn new_with_commitment(
+        endpoint: String,
+        commitment: CommitmentConfig,
+    ) -> Result<Connection> {
+        web3_construct(
+            "Connection",
+            &[
+                &JsValue::from(endpoint),
+                &commitment_to_jsvalue(Some(commitment)),
+            ],
+        )
+    }
+
+    /// Create Connection
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Connection.html)
+    ///
+    pub fn new_with_config(endpoint: String, config: &ConnectionConfig) -> Result<Connection> {
+        web3_construct("Connection", &[&JsValue::from(endpoint), config])
+    }
+
     pub async fn get_latest_block_hash(&self) -> Result<LatestBlockhashInfo> {
         Ok(self.get_latest_block_hash_impl().await?.into())
     }
 
+    pub async fn get_latest_block_hash_with_commitment(
+        &self,
+        commitment: CommitmentConfig,
+    ) -> Result<LatestBlockhashInfo> {
+        Ok(self
+            .get_latest_block_hash_with_commitment_impl(commitment_to_jsvalue(Some(commitment)))
+            .await?
+            .into())
+    }
+
+    /// Fetch the latest blockhash together with the slot at which it was observed
+    pub async fn get_latest_block_hash_and_context(
+        &self,
+        commitment: Option<CommitmentConfig>,
+    ) -> Result<(Slot, LatestBlockhashInfo)> {
+        let response = self
+            .get_latest_block_hash_and_context_impl(commitment_to_jsvalue(commitment))
+            .await?;
+        let context = Reflect::get(&response, &JsValue::from("context"))?;
+        let slot = u64_from_jsvalue(&Reflect::get(&context, &JsValue::from("slot"))?, "slot")?;
+        let value = Reflect::get(&response, &JsValue::from("value"))?;
+        Ok((slot, value.into()))
+    }
+
+    /// The default commitment used for requests
+    pub fn commitment(&self) -> Result<Option<CommitmentLevel>> {
+        Ok(self.commitment_impl().map(|s| s.parse()).transpose()?)
+    }
+
+    /// Wait for the transaction to reach `commitment` or for the blockhash
+    /// described by `latest_blockhash` to expire.
+    pub async fn confirm_transaction(
+        &self,
+        signature: &Signature,
+        latest_blockhash: &LatestBlockhashInfo,
+        commitment: Option<CommitmentConfig>,
+    ) -> Result<TransactionConfirmation> {
+        let strategy = BlockheightBasedTransactionConfirmationStrategy::new()
+            .signature(signature)
+            .blockhash(&latest_blockhash.block_hash()?)
+            .last_valid_block_height(latest_blockhash.last_valid_block_height()?);
+
+        let response = match self
+            .confirm_transaction_impl(strategy, commitment_to_jsvalue(commitment))
+            .await
+        {
+            Ok(response) => response,
+            Err(err) if err.js_error_info().is_some_and(is_blockheight_exceeded) => {
+                return Ok(TransactionConfirmation::Expired {
+                    signature: *signature,
+                })
+            }
+            Err(err) => return Err(err),
+        };
+
+        let context = Reflect::get(&response, &JsValue::from("context"))?;
+        let slot = Reflect::get(&context, &JsValue::from("slot"))?;
+        let slot = u64_from_jsvalue(&slot, "context.slot")?;
+        let value = Reflect::get(&response, &JsValue::from("value"))?;
+        let err = Reflect::get(&value, &JsValue::from("err"))?;
+
+        match transaction_error_from_jsvalue(&err)? {
+            Some(err) => Ok(TransactionConfirmation::Failed {
+                signature: *signature,
+                slot,
+                err,
+            }),
+            None => Ok(TransactionConfirmation::Confirmed {
+                signature: *signature,
+                slot,
+                commitment: match commitment {
+                    Some(commitment) => commitment.commitment,
+                    None => self.commitment()?.unwrap_or_default(),
+                },
+            }),
+        }
+    }
+
+    /// Send a signed [`Transaction`] and wait for it to be confirmed, using the
+    /// `lastValidBlockHeight` of `latest_blockhash` to detect expiry.
+    pub async fn send_and_confirm(
+        &self,
+        tx: &Transaction,
+        latest_blockhash: &LatestBlockhashInfo,
+        commitment: Option<CommitmentConfig>,
+    ) -> Result<TransactionConfirmation> {
+        let bytes = Uint8Array::new(&tx.try_serialize(SerializeConfig::new())?);
+        let signature = self
+            .send_raw_transaction(bytes.into())
+            .await?
+            .as_string()
+            .ok_or_else(|| JsValue::from("Invalid transaction signature"))?
+            .parse()?;
+        self.confirm_transaction(&signature, latest_blockhash, commitment)
+            .await
+    }
+
+    #[allow(clippy::unnecessary_fallible_conversions)]
     pub async fn get_account_info(&self, pubkey: &Pubkey) -> Result<Account> {
         let value = self
             .get_account_info_impl(PublicKey::try_from(pubkey)?.into())
@@ -136,6 +527,52 @@ impl Connection {
         account.try_into()
     }
 
+    /// Fetch multiple accounts, returning `None` for accounts that do not exist.
+    /// Results are in the same order as `pubkeys`.
+    pub async fn get_multiple_accounts(&self, pubkeys: &[Pubkey]) -> Result<Vec<Option<Account>>> {
+        self.get_multiple_accounts_with_concurrency(pubkeys, DEFAULT_MULTIPLE_ACCOUNTS_CONCURRENCY)
+            .await
+    }
+
+    /// Fetch multiple accounts in chunks of [`MAX_MULTIPLE_ACCOUNTS`], keeping
+    /// at most `concurrency` requests in flight. Results are in the same order as `pubkeys`.
+    pub async fn get_multiple_accounts_with_concurrency(
+        &self,
+        pubkeys: &[Pubkey],
+        concurrency: usize,
+    ) -> Result<Vec<Option<Account>>> {
+        let chunks = stream::iter(pubkeys.chunks(MAX_MULTIPLE_ACCOUNTS))
+            .map(|chunk| self.get_multiple_accounts_chunk(chunk))
+            .buffered(concurrency.max(1))
+            .try_collect::<Vec<_>>()
+            .await?;
+        Ok(chunks.into_iter().flatten().collect())
+    }
+
+    async fn get_multiple_accounts_chunk(
+        &self,
+        pubkeys: &[Pubkey],
+    ) -> Result<Vec<Option<Account>>> {
+        let list = Array::new();
+        for pubkey in pubkeys {
+            list.push(&pubkey_to_jsvalue(pubkey)?);
+        }
+        let res = self.get_multiple_accounts_info_impl(list).await?;
+        let array = Array::from(&res);
+        if array.length() as usize != pubkeys.len() {
+            return Err(JsValue::from("Invalid getMultipleAccountsInfo response").into());
+        }
+        let mut result = Vec::with_capacity(pubkeys.len());
+        for item in array.iter() {
+            if item.is_object() {
+                result.push(Some(ProgramAccount::from(item).try_into()?));
+            } else {
+                result.push(None);
+            }
+        }
+        Ok(result)
+    }
+
     pub async fn get_account_info_with_options(
         &self,
         pubkey: &Pubkey,
@@ -145,6 +582,120 @@ impl Connection {
             .await
     }
 
+    /// Balance of the account in lamports
+    pub async fn get_balance(
+        &self,
+        pubkey: &Pubkey,
+        commitment: Option<CommitmentConfig>,
+    ) -> Result<u64> {
+        let value = self
+            .get_balance_impl(
+                pubkey_to_jsvalue(pubkey)?,
+                commitment_to_jsvalue(commitment),
+            )
+            .await?;
+        u64_from_jsvalue(&value, "balance")
+    }
+
+    /// Minimum balance in lamports that makes an account holding `data_len` bytes rent exempt
+    pub async fn get_minimum_balance_for_rent_exemption(
+        &self,
+        data_len: usize,
+        commitment: Option<CommitmentConfig>,
+    ) -> Result<u64> {
+        let value = self
+            .get_minimum_balance_for_rent_exemption_impl(
+                data_len as f64,
+                commitment_to_jsvalue(commitment),
+            )
+            .await?;
+        u64_from_jsvalue(&value, "minimumBalanceForRentExemption")
+    }
+
+    /// Fetch and decode the nonce account at `nonce_pubkey`; the account must be
+    /// owned by the System Program and hold an initialized nonce
+    pub async fn get_nonce_account(&self, nonce_pubkey: &Pubkey) -> Result<NonceAccount> {
+        let account = self.get_account_info(nonce_pubkey).await?;
+        if account.owner != solana_sdk::system_program::id() {
+            return Err(JsValue::from(format!(
+                "Account {nonce_pubkey} is not owned by the System Program"
+            ))
+            .into());
+        }
+        nonce_data_from_account_data(&account.data)?;
+        NonceAccount::from_account_data(&account.data)
+    }
+
+    /// Build the transaction creating a rent-exempt nonce account owned by
+    /// `authorized_pubkey`; it must be signed by `from_pubkey` and `nonce_pubkey`
+    pub async fn create_nonce_account(
+        &self,
+        from_pubkey: &Pubkey,
+        nonce_pubkey: &Pubkey,
+        authorized_pubkey: &Pubkey,
+    ) -> Result<Transaction> {
+        let lamports = self
+            .get_minimum_balance_for_rent_exemption(NONCE_ACCOUNT_LENGTH, None)
+            .await?;
+        SystemProgram::create_nonce_account(from_pubkey, nonce_pubkey, authorized_pubkey, lamports)
+    }
+
+    /// Fee in lamports the cluster would charge to process `message`, `None`
+    /// if its blockhash is no longer valid. Legacy transactions are compiled
+    /// into their `Message`, so `feePayer` and `recentBlockhash` must be set.
+    pub async fn get_fee_for_message<'a>(
+        &self,
+        message: impl Into<FeeMessage<'a>>,
+        commitment: Option<CommitmentConfig>,
+    ) -> Result<Option<u64>> {
+        let message = match message.into() {
+            FeeMessage::Legacy(tx) => tx.compile_message()?,
+            FeeMessage::V0(message) => message.clone().into(),
+        };
+        let response = self
+            .get_fee_for_message_impl(&message, commitment_to_jsvalue(commitment))
+            .await?;
+        let value = Reflect::get(&response, &JsValue::from("value"))?;
+        if value.is_null() || value.is_undefined() {
+            return Ok(None);
+        }
+        Ok(Some(u64_from_jsvalue(&value, "fee")?))
+    }
+
+    /// Prioritization fees (micro-lamports per compute unit) paid in recent
+    /// slots, as `(slot, fee)` pairs. When `locked_writable_accounts` is not
+    /// empty, only transactions locking all of these accounts are considered.
+    ///
+    /// `getRecentPrioritizationFees` takes no commitment; the RPC node reports
+    /// from its own recent block cache.
+    pub async fn get_recent_prioritization_fees(
+        &self,
+        locked_writable_accounts: &[Pubkey],
+    ) -> Result<Vec<(Slot, u64)>> {
+        let config = Object::new();
+        if !locked_writable_accounts.is_empty() {
+            let list = Array::new();
+            for pubkey in locked_writable_accounts {
+                list.push(&pubkey_to_jsvalue(pubkey)?);
+            }
+            Reflect::set(&config, &JsValue::from("lockedWritableAccounts"), &list)?;
+        }
+        let res = self
+            .get_recent_prioritization_fees_impl(config.into())
+            .await?;
+
+        let mut result = vec![];
+        for item in Array::from(&res).iter() {
+            let slot = Reflect::get(&item, &JsValue::from("slot"))?;
+            let fee = Reflect::get(&item, &JsValue::from("prioritizationFee"))?;
+            result.push((
+                u64_from_jsvalue(&slot, "slot")?,
+                u64_from_jsvalue(&fee, "prioritizationFee")?,
+            ));
+        }
+        Ok(result)
+    }
+
     pub async fn send_raw_transaction_with_options(
         &self,
         tx: JsValue,
@@ -154,6 +705,99 @@ impl Connection {
             .await
     }
 
+    pub async fn send_versioned_transaction(
+        &self,
+        tx: &VersionedTransaction,
+        options: Option<SendRawTxOptions>,
+    ) -> Result<Signature> {
+        let options = options.map(JsValue::from).unwrap_or(JsValue::UNDEFINED);
+        let signature = self
+            .send_versioned_transaction_impl(tx.clone(), options)
+            .await?;
+        signature_from_jsvalue(&signature)
+    }
+
+    /// Simulate a legacy or versioned transaction without submitting it.
+    ///
+    /// Legacy transactions are converted to a [`VersionedTransaction`] so the
+    /// same `config` applies to both; they do not need to be signed when
+    /// `sigVerify` is disabled.
+    pub async fn simulate_transaction<'a>(
+        &self,
+        tx: impl Into<SimulatedTransaction<'a>>,
+        config: Option<SimulateTransactionConfig>,
+    ) -> Result<SimulateTransactionResult> {
+        let tx = match tx.into() {
+            SimulatedTransaction::Legacy(tx) => VersionedTransaction::deserialize(&tx.to_bytes()?)?,
+            SimulatedTransaction::Versioned(tx) => tx.clone(),
+        };
+        let config = config.map(JsValue::from).unwrap_or(JsValue::UNDEFINED);
+        let response = self.simulate_transaction_impl(&tx, config).await?;
+        let value = Reflect::get(&response, &JsValue::from("value"))?;
+        SimulateTransactionResult::try_from(&value)
+    }
+
+    /// Signatures of transactions involving `address`, newest first
+    pub async fn get_signatures_for_address(
+        &self,
+        address: &Pubkey,
+        options: Option<SignaturesForAddressOptions>,
+        commitment: Option<CommitmentConfig>,
+    ) -> Result<Vec<ConfirmedSignatureInfo>> {
+        let options = options.map(JsValue::from).unwrap_or(JsValue::UNDEFINED);
+        let res = self
+            .get_signatures_for_address_impl(
+                pubkey_to_jsvalue(address)?,
+                options,
+                commitment_to_jsvalue(commitment),
+            )
+            .await?;
+        Array::from(&res)
+            .iter()
+            .map(|info| ConfirmedSignatureInfo::try_from(&info))
+            .collect()
+    }
+
+    /// Pager walking the signatures of `address` backwards in time
+    pub fn signatures_for_address(&self, address: &Pubkey) -> SignaturesForAddressPager {
+        SignaturesForAddressPager::new(self.clone(), *address)
+    }
+
+    /// Fetch a confirmed transaction, `None` if it is not found. Both legacy
+    /// and v0 transactions are returned (`maxSupportedTransactionVersion: 0`).
+    pub async fn get_transaction(
+        &self,
+        signature: &Signature,
+        commitment: Option<CommitmentConfig>,
+    ) -> Result<Option<ConfirmedTransactionWithStatusMeta>> {
+        let mut config = GetVersionedTransactionConfig::new().max_supported_transaction_version(0);
+        if let Some(commitment) = commitment {
+            config = config.commitment(commitment);
+        }
+        let res = self
+            .get_transaction_impl(signature.to_string(), config)
+            .await?;
+        if !res.is_object() {
+            return Ok(None);
+        }
+        Ok(Some(ConfirmedTransactionWithStatusMeta::try_from(&res)?))
+    }
+
+    pub async fn get_address_lookup_table(
+        &self,
+        pubkey: &Pubkey,
+    ) -> Result<Option<AddressLookupTableAccount>> {
+        let response = self
+            .get_address_lookup_table_impl(pubkey_to_jsvalue(pubkey)?)
+            .await?;
+        let value = Reflect::get(&response, &JsValue::from("value"))?;
+        if value.is_null() || value.is_undefined() {
+            return Ok(None);
+        }
+        Ok(Some(value.into()))
+    }
+
+    #[allow(irrefutable_let_patterns, clippy::unnecessary_fallible_conversions)]
     pub async fn get_program_accounts_with_config(
         &self,
         pubkey: &Pubkey,
@@ -182,6 +826,156 @@ impl Connection {
 
         Ok(result)
     }
+
+    pub fn on_account_change(
+        &self,
+        pubkey: &Pubkey,
+        commitment: Option<CommitmentConfig>,
+    ) -> Result<Subscription<(Pubkey, Account)>> {
+        let key = *pubkey;
+        Subscription::try_new(
+            self,
+            ListenerKind::AccountChange,
+            move |account, _context| Ok((key, ProgramAccount::from(account).try_into()?)),
+            |callback| {
+                self.on_account_change_impl(
+                    pubkey_to_jsvalue(pubkey)?,
+                    callback.as_ref().unchecked_ref(),
+                    commitment_to_jsvalue(commitment),
+                )
+            },
+        )
+    }
+
+    pub fn on_program_account_change(
+        &self,
+        program_id: &Pubkey,
+        commitment: Option<CommitmentConfig>,
+        filters: Option<Vec<RpcFilterType>>,
+    ) -> Result<Subscription<(Pubkey, Account)>> {
+        let filters = match filters {
+            Some(filters) => filters_to_jsvalue(&filters)?,
+            None => JsValue::UNDEFINED,
+        };
+        Subscription::try_new(
+            self,
+            ListenerKind::ProgramAccountChange,
+            |keyed_account, _context| {
+                let pubkey = Reflect::get(&keyed_account, &JsValue::from("accountId"))?;
+                let account = Reflect::get(&keyed_account, &JsValue::from("accountInfo"))?;
+                Ok((
+                    PublicKey::from(pubkey).try_into()?,
+                    ProgramAccount::from(account).try_into()?,
+                ))
+            },
+            |callback| {
+                self.on_program_account_change_impl(
+                    pubkey_to_jsvalue(program_id)?,
+                    callback.as_ref().unchecked_ref(),
+                    commitment_to_jsvalue(commitment),
+                    filters,
+                )
+            },
+        )
+    }
+
+    pub fn on_logs(
+        &self,
+        filter: &LogsFilter,
+        commitment: Option<CommitmentConfig>,
+    ) -> Result<Subscription<Logs>> {
+        Subscription::try_new(
+            self,
+            ListenerKind::Logs,
+            |logs, _context| logs.try_into(),
+            |callback| {
+                self.on_logs_impl(
+                    filter.try_into()?,
+                    callback.as_ref().unchecked_ref(),
+                    commitment_to_jsvalue(commitment),
+                )
+            },
+        )
+    }
+
+    pub fn on_slot_change(&self) -> Result<Subscription<SlotInfo>> {
+        Subscription::try_new(
+            self,
+            ListenerKind::SlotChange,
+            |slot_info, _context| slot_info.try_into(),
+            |callback| self.on_slot_change_impl(callback.as_ref().unchecked_ref()),
+        )
+    }
+
+    pub fn on_signature(
+        &self,
+        signature: &Signature,
+        commitment: Option<CommitmentConfig>,
+    ) -> Result<Subscription<SignatureResult>> {
+        Subscription::try_new(
+            self,
+            ListenerKind::Signature,
+            |result, _context| result.try_into(),
+            |callback| {
+                self.on_signature_impl(
+                    signature.to_string(),
+                    callback.as_ref().unchecked_ref(),
+                    commitment_to_jsvalue(commitment),
+                )
+            },
+        )
+    }
+
+    pub fn on_root_change(&self) -> Result<Subscription<Slot>> {
+        Subscription::try_new(
+            self,
+            ListenerKind::RootChange,
+            |root, _context| {
+                root.as_f64()
+                    .map(|root| root as Slot)
+                    .ok_or_else(|| JsValue::from("Invalid root notification").into())
+            },
+            |callback| self.on_root_change_impl(callback.as_ref().unchecked_ref()),
+        )
+    }
+}
+
+/// Message accepted by [`Connection::get_fee_for_message`]
+#[derive(Debug, Clone, Copy)]
+pub enum FeeMessage<'a> {
+    Legacy(&'a Transaction),
+    V0(&'a MessageV0),
+}
+
+impl<'a> From<&'a Transaction> for FeeMessage<'a> {
+    fn from(tx: &'a Transaction) -> Self {
+        FeeMessage::Legacy(tx)
+    }
+}
+
+impl<'a> From<&'a MessageV0> for FeeMessage<'a> {
+    fn from(message: &'a MessageV0) -> Self {
+        FeeMessage::V0(message)
+    }
+}
+
+/// Transaction accepted by [`Connection::simulate_transaction`]
+#[derive(Debug, Clone, Copy)]
+pub enum SimulatedTransaction<'a> {
+    Legacy(&'a Transaction),
+    Versioned(&'a VersionedTransaction),
+}
+
+impl<'a> From<&'a Transaction> for SimulatedTransaction<'a> {
+    fn from(tx: &'a Transaction) -> Self {
+        SimulatedTransaction::Legacy(tx)
+    }
+}
+
+impl<'a> From<&'a VersionedTransaction> for SimulatedTransaction<'a> {
+    fn from(tx: &'a VersionedTransaction) -> Self {
+        SimulatedTransaction::Versioned(tx)
+    }
 }
 
 impl OptionsTrait for SendRawTxOptions {}
@@ -192,3 +986,73 @@ impl SendRawTxOptions {
         self.set("skipPreflight", JsValue::from(skip_preflight))
     }
 }
+
+impl LatestBlockhashInfo {
+    /// The blockhash
+    pub fn block_hash(&self) -> Result<Hash> {
+        self.block_hash_impl()
+            .as_string()
+            .ok_or_else(|| Error::InvalidField("blockhash".to_string()))?
+            .parse()
+            .map_err(|_| Error::InvalidField("blockhash".to_string()))
+    }
+
+    /// Last block height at which the blockhash is valid
+    pub fn last_valid_block_height(&self) -> Result<u64> {
+        u64_from_jsvalue(&self.last_valid_block_height_impl(), "lastValidBlockHeight")
+    }
+}
+
+impl OptionsTrait for ConnectionConfig {}
+
+impl ConnectionConfig {
+    /// Set commitment
+    pub fn commitment(self, commitment: CommitmentConfig) -> Self {
+        self.set("commitment", commitment.commitment.to_string().into())
+    }
+
+    /// Set wsEndpoint
+    pub fn ws_endpoint(self, ws_endpoint: &str) -> Self {
+        self.set("wsEndpoint", JsValue::from(ws_endpoint))
+    }
+
+    /// Set httpHeaders
+    pub fn http_headers(self, http_headers: &[(&str, &str)]) -> Result<Self> {
+        let headers = Object::new();
+        for (name, value) in http_headers {
+            Reflect::set(&headers, &JsValue::from(*name), &JsValue::from(*value))?;
+        }
+        Ok(self.set("httpHeaders", headers.into()))
+    }
+
+    /// Set disableRetryOnRateLimit
+    pub fn disable_retry_on_rate_limit(self, disable_retry_on_rate_limit: bool) -> Self {
+        self.set(
+            "disableRetryOnRateLimit",
+            JsValue::from(disable_retry_on_rate_limit),
+        )
+    }
+
+    /// Set confirmTransactionInitialTimeout
+    pub fn confirm_transaction_initial_timeout(self, timeout: std::time::Duration) -> Self {
+        self.set(
+            "confirmTransactionInitialTimeout",
+            JsValue::from(timeout.as_millis() as f64),
+        )
+    }
+
+    /// Set fetch, a `fetch`-compatible function used for all HTTP requests
+    pub fn fetch(self, fetch: &Function) -> Self {
+        self.set("fetch", fetch.into())
+    }
+}
+
+fn commitment_to_jsvalue(commitment: Option<CommitmentConfig>) -> JsValue {
+    commitment
+        .map(|commitment| JsValue::from(commitment.commitment.to_string()))
+        .unwrap_or(JsValue::UNDEFINED)
+}
+
+fn is_blockheight_exceeded(err: &crate::error::JsErrorInfo) -> bool {
+    err.name == "TransactionExpiredBlockheightExceededError"
+}
@@ -1,22 +1,486 @@
 //!
 //! [`Error`] variants produced by this crate.
 //!
+use crate::utils::{transaction_error_from_jsvalue, TransactionStatusError};
+use js_sys::Reflect;
+use solana_program::instruction::InstructionError;
 use solana_program::pubkey::ParsePubkeyError;
+use solana_sdk::commitment_config::ParseCommitmentLevelError;
+use solana_sdk::signature::ParseSignatureError;
+use solana_sdk::transaction::TransactionError;
 use wasm_bindgen::prelude::*;
 
+/// JSON-RPC error code returned when `sendTransaction` preflight simulation fails
+pub const JSON_RPC_SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE: i64 = -32002;
+/// JSON-RPC error code returned when the RPC node is behind or unhealthy
+pub const JSON_RPC_SERVER_ERROR_NODE_UNHEALTHY: i64 = -32005;
+/// EIP-1193 `userRejectedRequest` code used by Solana wallets
+pub const USER_REJECTED_REQUEST_CODE: i64 = 4001;
+/// Name of the error wallet adapters throw when the user closes the wallet window
+const WALLET_WINDOW_CLOSED_ERROR_NAME: &str = "WalletWindowClosedError";
+
+/// Owned copy of a JavaScript error, taken when the error crosses into Rust
+/// so that [`Error`] stays `Send + Sync`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct JsErrorInfo {
+    pub name: String,
+    pub message: String,
+    pub stack: Option<String>,
+    pub code: Option<String>,
+    pub logs: Vec<String>,
+}
+
+impl JsErrorInfo {
+    /// Copy the `name`, `message`, `stack`, `code` and `logs` properties of
+    /// a thrown value; values that are not objects become the message.
+    /// Wallet adapter errors carry the wallet's own `code` on the wrapped
+    /// `error`, which is used when the outer error has none.
+    pub fn from_js_value(value: &JsValue) -> Self {
+        if !value.is_object() {
+            let message = value
+                .as_string()
+                .or_else(|| js_sys::JSON::stringify(value).ok().map(String::from))
+                .unwrap_or_else(|| format!("{value:?}"));
+            return Self {
+                message,
+                ..Default::default()
+            };
+        }
+
+        let get = |name: &str| {
+            Reflect::get(value, &JsValue::from(name))
+                .ok()
+                .filter(|value| !value.is_null() && !value.is_undefined())
+        };
+        let string = |name: &str| get(name).and_then(|value| value.as_string());
+        let logs = get("logs")
+            .or_else(|| get("transactionLogs"))
+            .filter(|logs| logs.is_array())
+            .map(|logs| {
+                js_sys::Array::from(&logs)
+                    .iter()
+                    .filter_map(|log| log.as_string())
+                    .collect()
+            })
+            .unwrap_or_default();
+        Self {
+            name: string("name").unwrap_or_default(),
+            message: string("message").unwrap_or_default(),
+            stack: string("stack"),
+            code: get("code")
+                .or_else(|| {
+                    get("error")
+                        .and_then(|error| Reflect::get(&error, &JsValue::from("code")).ok())
+                        .filter(|code| !code.is_null() && !code.is_undefined())
+                })
+                .map(|code| match code.as_f64() {
+                    Some(code) => code.to_string(),
+                    None => code.as_string().unwrap_or_default(),
+                }),
+            logs,
+        }
+    }
+
+    /// Rebuild a JavaScript `Error` carrying the copied properties
+    pub fn to_js_value(&self) -> JsValue {
+        let error = js_sys::Error::new(&self.message);
+        if !self.name.is_empty() {
+            error.set_name(&self.name);
+        }
+        let set = |name: &str, value: JsValue| {
+            let _ = Reflect::set(&error, &JsValue::from(name), &value);
+        };
+        if let Some(stack) = &self.stack {
+            set("stack", stack.into());
+        }
+        if let Some(code) = &self.code {
+            match code.parse::<f64>() {
+                Ok(code) => set("code", code.into()),
+                Err(_) => set("code", code.into()),
+            }
+        }
+        if !self.logs.is_empty() {
+            let logs: js_sys::Array = self.logs.iter().map(JsValue::from).collect();
+            set("logs", logs.into());
+        }
+        error.into()
+    }
+}
+
+impl std::fmt::Display for JsErrorInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.name.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", self.name, self.message)
+        }
+    }
+}
+
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
-    #[error("{0:?}")]
-    JsValue(JsValue),
+    #[error("{0}")]
+    JsValue(Box<JsErrorInfo>),
+
+    #[error("{message}")]
+    SendTransaction {
+        message: String,
+        logs: Vec<String>,
+        err: Option<TransactionError>,
+        info: Box<JsErrorInfo>,
+    },
+
+    #[error("JSON-RPC error {code}: {message}")]
+    JsonRpc {
+        code: i64,
+        message: String,
+        err: Option<TransactionError>,
+        info: Box<JsErrorInfo>,
+    },
+
+    #[error("Transaction failed: {err}")]
+    Transaction {
+        err: TransactionError,
+        info: Box<JsErrorInfo>,
+    },
+
+    #[error("solana-web3-sys is not initialized; please use `solana::init_solana_web3_sys()` to initialize")]
+    NotInitialized,
+
+    #[error("Value of `{0}` does not fit in u64")]
+    Overflow(String),
+
+    #[error("Invalid value for `{0}`")]
+    InvalidField(String),
 
     #[error("ParsePubkeyError: {0:?}")]
     ParsePubkeyError(#[from] ParsePubkeyError),
+
+    #[error("ParseSignatureError: {0:?}")]
+    ParseSignatureError(#[from] ParseSignatureError),
+
+    #[error("ParseCommitmentLevelError: {0}")]
+    ParseCommitmentLevelError(#[from] ParseCommitmentLevelError),
+
+    #[error("Wallet rejected the request: {message}")]
+    WalletRejected {
+        message: String,
+        info: Box<JsErrorInfo>,
+    },
+
+    #[error("Sign-in: {0}")]
+    SignIn(String),
+
+    #[error("Invalid filter: {0}")]
+    InvalidFilter(String),
+
+    #[error("Bincode: {0}")]
+    Bincode(#[from] bincode::Error),
+
+    #[error("SerdeJson: {0}")]
+    SerdeJson(#[from] serde_json::Error),
 }
 
-unsafe impl Send for Error {}
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Error>();
+};
+
+impl Error {
+    /// The JavaScript error this error was decoded from, if any
+    pub fn js_error_info(&self) -> Option<&JsErrorInfo> {
+        match self {
+            Error::JsValue(info)
+            | Error::SendTransaction { info, .. }
+            | Error::JsonRpc { info, .. }
+            | Error::Transaction { info, .. }
+            | Error::WalletRejected { info, .. } => Some(info.as_ref()),
+            _ => None,
+        }
+    }
+
+    /// Rebuild the JavaScript error this error was decoded from, if any
+    pub fn js_value(&self) -> Option<JsValue> {
+        self.js_error_info().map(JsErrorInfo::to_js_value)
+    }
+
+    /// The [`TransactionError`] reported by the cluster, if any
+    pub fn transaction_error(&self) -> Option<&TransactionError> {
+        match self {
+            Error::SendTransaction { err, .. } | Error::JsonRpc { err, .. } => err.as_ref(),
+            Error::Transaction { err, .. } => Some(err),
+            _ => None,
+        }
+    }
+
+    /// Program logs attached to a failed `sendTransaction` preflight
+    pub fn logs(&self) -> &[String] {
+        match self {
+            Error::SendTransaction { logs, .. } => logs,
+            _ => &[],
+        }
+    }
+
+    /// JSON-RPC error code, if any
+    pub fn code(&self) -> Option<i64> {
+        match self {
+            Error::JsonRpc { code, .. } => Some(*code),
+            _ => None,
+        }
+    }
+
+    /// `true` if the request may succeed when sent again, e.g. to another
+    /// or a recovered RPC node
+    pub fn is_retryable(&self) -> bool {
+        self.code() == Some(JSON_RPC_SERVER_ERROR_NODE_UNHEALTHY)
+    }
+}
 
 impl From<JsValue> for Error {
     fn from(value: JsValue) -> Self {
-        Self::JsValue(value)
+        let info = Box::new(JsErrorInfo::from_js_value(&value));
+        if !value.is_object() {
+            return Self::JsValue(info);
+        }
+
+        let get = |name: &str| Reflect::get(&value, &JsValue::from(name)).ok();
+        let err = get("err")
+            .or_else(|| get("data").and_then(|data| Reflect::get(&data, &"err".into()).ok()))
+            .and_then(|err| transaction_error_from_jsvalue(&err).ok().flatten())
+            .and_then(TransactionStatusError::into_transaction_error);
+        let has_logs = get("logs")
+            .or_else(|| get("transactionLogs"))
+            .is_some_and(|logs| logs.is_array());
+        Self::from_js_error_info(info, err, has_logs)
+    }
+}
+
+impl Error {
+    /// Classify a copied JavaScript error given the `TransactionError` decoded
+    /// from its `err` property and whether it carried a `logs` array
+    fn from_js_error_info(
+        info: Box<JsErrorInfo>,
+        err: Option<TransactionError>,
+        has_logs: bool,
+    ) -> Self {
+        if is_wallet_rejection(&info) {
+            return Self::WalletRejected {
+                message: info.message.clone(),
+                info,
+            };
+        }
+
+        let code = info.code.as_ref().and_then(|code| code.parse::<i64>().ok());
+        let err = err.or_else(|| transaction_error_from_message(&info.message));
+        if has_logs {
+            return Self::SendTransaction {
+                message: info.message.clone(),
+                logs: info.logs.clone(),
+                err,
+                info,
+            };
+        }
+
+        if let Some(code) = code {
+            return Self::JsonRpc {
+                code,
+                message: info.message.clone(),
+                err,
+                info,
+            };
+        }
+
+        match err {
+            Some(err) => Self::Transaction { err, info },
+            None => Self::JsValue(info),
+        }
+    }
+}
+
+/// `true` if a wallet threw the error because the user declined the request,
+/// identified by the EIP-1193 [`USER_REJECTED_REQUEST_CODE`] or a closed
+/// wallet window
+pub fn is_wallet_rejection(info: &JsErrorInfo) -> bool {
+    info.code.as_deref() == Some(USER_REJECTED_REQUEST_CODE.to_string().as_str())
+        || info.name == WALLET_WINDOW_CLOSED_ERROR_NAME
+}
+
+impl From<Error> for JsValue {
+    fn from(err: Error) -> Self {
+        err.js_value()
+            .unwrap_or_else(|| js_sys::Error::new(&err.to_string()).into())
+    }
+}
+
+/// Recover a [`TransactionError`] from error messages produced by web3.js:
+/// `Transaction <signature> failed ({"err":...})` from `sendAndConfirmTransaction`
+/// and `Error processing Instruction <n>: custom program error: 0x<code>` from
+/// preflight simulation.
+fn transaction_error_from_message(message: &str) -> Option<TransactionError> {
+    if let (Some(start), Some(end)) = (message.find("({"), message.rfind("})")) {
+        let err = serde_json::from_str::<serde_json::Value>(&message[start + 1..end + 1])
+            .ok()
+            .and_then(|status| serde_json::from_value(status.get("err")?.clone()).ok());
+        if err.is_some() {
+            return err;
+        }
+    }
+
+    let (_, rest) = message.split_once("Error processing Instruction ")?;
+    let (index, rest) = rest.split_once(": custom program error: 0x")?;
+    let index = index.parse().ok()?;
+    let code: String = rest.chars().take_while(|c| c.is_ascii_hexdigit()).collect();
+    let code = u32::from_str_radix(&code, 16).ok()?;
+    Some(TransactionError::InstructionError(
+        index,
+        InstructionError::Custom(code),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn info(name: &str, message: &str, code: Option<&str>) -> Box<JsErrorInfo> {
+        Box::new(JsErrorInfo {
+            name: name.to_string(),
+            message: message.to_string(),
+            code: code.map(String::from),
+            ..Default::default()
+        })
+    }
+
+    const CUSTOM_ERROR: &str = "failed to send transaction: Transaction simulation failed: \
+        Error processing Instruction 1: custom program error: 0x1771";
+
+    #[test]
+    fn json_rpc_code() {
+        let err = Error::from_js_error_info(
+            info("SolanaJSONRPCError", "Node is behind", Some("-32005")),
+            None,
+            false,
+        );
+        assert!(
+            matches!(err, Error::JsonRpc { code, err: None, .. } if code == JSON_RPC_SERVER_ERROR_NODE_UNHEALTHY)
+        );
+        assert!(err.is_retryable());
+
+        let err = Error::from_js_error_info(
+            info("SolanaJSONRPCError", "Preflight failed", Some("-32002")),
+            Some(TransactionError::BlockhashNotFound),
+            false,
+        );
+        assert_eq!(
+            err.code(),
+            Some(JSON_RPC_SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE)
+        );
+        assert_eq!(
+            err.transaction_error(),
+            Some(&TransactionError::BlockhashNotFound)
+        );
+        assert!(!err.is_retryable());
+    }
+
+    #[test]
+    fn custom_program_error() {
+        let err = Error::from_js_error_info(info("Error", CUSTOM_ERROR, None), None, false);
+        assert!(matches!(
+            err,
+            Error::Transaction {
+                err: TransactionError::InstructionError(1, InstructionError::Custom(0x1771)),
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn send_transaction_logs() {
+        let mut info = info("SendTransactionError", CUSTOM_ERROR, None);
+        info.logs = vec!["Program log: Error".to_string()];
+        let err = Error::from_js_error_info(info, None, true);
+        assert!(matches!(err, Error::SendTransaction { .. }));
+        assert_eq!(err.logs(), ["Program log: Error".to_string()]);
+        assert_eq!(
+            err.transaction_error(),
+            Some(&TransactionError::InstructionError(
+                1,
+                InstructionError::Custom(0x1771)
+            ))
+        );
+    }
+
+    #[test]
+    fn user_rejected() {
+        let err = Error::from_js_error_info(
+            info("Error", "User rejected the request.", Some("4001")),
+            None,
+            false,
+        );
+        assert!(
+            matches!(err, Error::WalletRejected { ref message, .. } if message == "User rejected the request.")
+        );
+        assert_eq!(err.code(), None);
+    }
+
+    #[test]
+    fn wallet_rejection_paths_agree() {
+        let cases = [
+            ("Error", Some("4001"), true),
+            ("WalletSignTransactionError", Some("4001"), true),
+            ("WalletWindowClosedError", None, true),
+            ("WalletSignTransactionError", Some("-32603"), false),
+            ("WalletSignMessageError", None, false),
+            ("WalletSignInError", None, false),
+        ];
+        for (name, code, rejected) in cases {
+            let err = Error::from_js_error_info(info(name, "failed", code), None, false);
+            assert_eq!(
+                matches!(err, Error::WalletRejected { .. }),
+                rejected,
+                "{name}"
+            );
+            let err = crate::wallet::wallet_error(Error::JsValue(info(name, "failed", code)));
+            assert_eq!(
+                matches!(err, Error::WalletRejected { .. }),
+                rejected,
+                "{name}"
+            );
+        }
+    }
+
+    #[test]
+    fn plain_string() {
+        let err = Error::from_js_error_info(info("", "boom", None), None, false);
+        assert!(matches!(err, Error::JsValue(ref info) if info.message == "boom"));
+        assert_eq!(err.to_string(), "boom");
+    }
+
+    #[test]
+    fn message_status() {
+        let message = r#"Transaction 5x failed ({"err":{"InstructionError":[0,{"Custom":6}]}})"#;
+        assert_eq!(
+            transaction_error_from_message(message),
+            Some(TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(6)
+            ))
+        );
+    }
+
+    #[test]
+    fn malformed_status_falls_through() {
+        let message = "Transaction failed ({not json}): \
+            Error processing Instruction 2: custom program error: 0x10 ({})";
+        assert_eq!(
+            transaction_error_from_message(message),
+            Some(TransactionError::InstructionError(
+                2,
+                InstructionError::Custom(16)
+            ))
+        );
+        assert_eq!(
+            transaction_error_from_message("Transaction failed ({bad})"),
+            None
+        );
     }
 }
@@ -0,0 +1,441 @@
+//!
+//! Transaction history (`getSignaturesForAddress`, `getTransaction`) bindings.
+//!
+
+use crate::connection::Connection;
+use crate::error::Error;
+use crate::imports::*;
+use js_sys::Reflect;
+use solana_sdk::commitment_config::{CommitmentConfig, CommitmentLevel};
+use solana_sdk::instruction::CompiledInstruction;
+use solana_sdk::message::v0::LoadedAddresses;
+use solana_sdk::message::VersionedMessage;
+use solana_sdk::signature::Signature;
+use solana_sdk::slot_history::Slot;
+use solana_sdk::transaction::VersionedTransaction;
+
+/// Maximum number of signatures returned by a single `getSignaturesForAddress` request
+pub const MAX_SIGNATURES_FOR_ADDRESS_LIMIT: usize = 1000;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(extends = Object)]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    /// SignaturesForAddressOptions
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/types/SignaturesForAddressOptions.html)
+    ///
+    pub type SignaturesForAddressOptions;
+
+    #[wasm_bindgen(extends = Object)]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    /// GetVersionedTransactionConfig
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/types/GetVersionedTransactionConfig.html)
+    ///
+    pub type GetVersionedTransactionConfig;
+}
+
+impl OptionsTrait for SignaturesForAddressOptions {}
+
+impl SignaturesForAddressOptions {
+    /// Set before
+    pub fn before(self, before: &Signature) -> Self {
+        self.set("before", before.to_string().into())
+    }
+
+    /// Set until
+    pub fn until(self, until: &Signature) -> Self {
+        self.set("until", until.to_string().into())
+    }
+
+    /// Set limit
+    pub fn limit(self, limit: usize) -> Self {
+        self.set("limit", JsValue::from(limit))
+    }
+
+    /// Set minContextSlot
+    pub fn min_context_slot(self, min_context_slot: Slot) -> Self {
+        self.set("minContextSlot", JsValue::from(min_context_slot as f64))
+    }
+}
+
+impl OptionsTrait for GetVersionedTransactionConfig {}
+
+impl GetVersionedTransactionConfig {
+    /// Set maxSupportedTransactionVersion
+    pub fn max_supported_transaction_version(self, version: u8) -> Self {
+        self.set("maxSupportedTransactionVersion", JsValue::from(version))
+    }
+
+    /// Set commitment
+    pub fn commitment(self, commitment: CommitmentConfig) -> Self {
+        self.set("commitment", commitment.commitment.to_string().into())
+    }
+}
+
+/// Signature entry returned by `getSignaturesForAddress`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfirmedSignatureInfo {
+    pub signature: Signature,
+    pub slot: Slot,
+    pub err: Option<TransactionStatusError>,
+    pub memo: Option<String>,
+    pub block_time: Option<i64>,
+    pub confirmation_status: Option<CommitmentLevel>,
+}
+
+impl TryFrom<&JsValue> for ConfirmedSignatureInfo {
+    type Error = Error;
+
+    fn try_from(value: &JsValue) -> Result<Self> {
+        let signature = get(value, "signature")?
+            .as_string()
+            .ok_or_else(|| Error::InvalidField("signature".to_string()))?;
+        let confirmation_status = get(value, "confirmationStatus")?
+            .as_string()
+            .map(|status| status.parse())
+            .transpose()?;
+        Ok(Self {
+            signature: signature.parse()?,
+            slot: u64_from_jsvalue(&get(value, "slot")?, "slot")?,
+            err: transaction_error_from_jsvalue(&get(value, "err")?)?,
+            memo: get(value, "memo")?.as_string(),
+            block_time: get(value, "blockTime")?.as_f64().map(|time| time as i64),
+            confirmation_status,
+        })
+    }
+}
+
+/// Walks the signatures of an address from newest to oldest, one
+/// `getSignaturesForAddress` request per page, until the `until`
+/// signature or the start of the history is reached.
+///
+/// Created by [`Connection::signatures_for_address`].
+pub struct SignaturesForAddressPager {
+    connection: Connection,
+    address: Pubkey,
+    before: Option<Signature>,
+    until: Option<Signature>,
+    limit: usize,
+    commitment: Option<CommitmentConfig>,
+    done: bool,
+}
+
+impl SignaturesForAddressPager {
+    pub(crate) fn new(connection: Connection, address: Pubkey) -> Self {
+        Self {
+            connection,
+            address,
+            before: None,
+            until: None,
+            limit: MAX_SIGNATURES_FOR_ADDRESS_LIMIT,
+            commitment: None,
+            done: false,
+        }
+    }
+
+    /// Start paging after (older than) `before`
+    pub fn before(mut self, before: &Signature) -> Self {
+        self.before = Some(*before);
+        self
+    }
+
+    /// Stop paging once `until` is reached; `until` itself is not returned
+    pub fn until(mut self, until: &Signature) -> Self {
+        self.until = Some(*until);
+        self
+    }
+
+    /// Number of signatures per page, capped at [`MAX_SIGNATURES_FOR_ADDRESS_LIMIT`]
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = limit.clamp(1, MAX_SIGNATURES_FOR_ADDRESS_LIMIT);
+        self
+    }
+
+    /// Commitment used for every page request
+    pub fn commitment(mut self, commitment: CommitmentConfig) -> Self {
+        self.commitment = Some(commitment);
+        self
+    }
+
+    /// Fetch the next (older) page, `None` once the cutoff has been reached
+    pub async fn next_page(&mut self) -> Result<Option<Vec<ConfirmedSignatureInfo>>> {
+        if self.done {
+            return Ok(None);
+        }
+
+        let mut options = SignaturesForAddressOptions::new().limit(self.limit);
+        if let Some(before) = &self.before {
+            options = options.before(before);
+        }
+        if let Some(until) = &self.until {
+            options = options.until(until);
+        }
+        let page = self
+            .connection
+            .get_signatures_for_address(&self.address, Some(options), self.commitment)
+            .await?;
+
+        if page.len() < self.limit {
+            self.done = true;
+        }
+        match page.last() {
+            Some(last) => self.before = Some(last.signature),
+            None => return Ok(None),
+        }
+        Ok(Some(page))
+    }
+
+    /// Fetch all remaining pages
+    pub async fn collect(mut self) -> Result<Vec<ConfirmedSignatureInfo>> {
+        let mut result = vec![];
+        while let Some(page) = self.next_page().await? {
+            result.extend(page);
+        }
+        Ok(result)
+    }
+}
+
+/// Instructions invoked by the instruction at `index` of the transaction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InnerInstructions {
+    pub index: u8,
+    pub instructions: Vec<CompiledInstruction>,
+}
+
+/// SPL Token balance of an account before or after the transaction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenBalance {
+    pub account_index: u8,
+    pub mint: Pubkey,
+    pub owner: Option<Pubkey>,
+    pub program_id: Option<Pubkey>,
+    pub amount: u64,
+    pub decimals: u8,
+}
+
+/// Status metadata of a confirmed transaction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionStatusMeta {
+    pub err: Option<TransactionStatusError>,
+    pub fee: u64,
+    pub pre_balances: Vec<u64>,
+    pub post_balances: Vec<u64>,
+    pub inner_instructions: Vec<InnerInstructions>,
+    pub log_messages: Vec<String>,
+    pub pre_token_balances: Vec<TokenBalance>,
+    pub post_token_balances: Vec<TokenBalance>,
+    pub loaded_addresses: LoadedAddresses,
+    pub compute_units_consumed: Option<u64>,
+}
+
+/// Confirmed transaction returned by [`Connection::get_transaction`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfirmedTransactionWithStatusMeta {
+    pub slot: Slot,
+    pub block_time: Option<i64>,
+    pub transaction: VersionedTransaction,
+    pub meta: Option<TransactionStatusMeta>,
+}
+
+impl TryFrom<&JsValue> for ConfirmedTransactionWithStatusMeta {
+    type Error = Error;
+
+    fn try_from(value: &JsValue) -> Result<Self> {
+        let transaction = get(value, "transaction")?;
+        let message = get(&transaction, "message")?;
+        let serialize = Reflect::get(&message, &JsValue::from("serialize"))?;
+        let bytes = js_sys::Function::from(serialize).call0(&message)?;
+        let message: VersionedMessage = bincode::deserialize(&Uint8Array::new(&bytes).to_vec())?;
+        let signatures = Array::from(&get(&transaction, "signatures")?)
+            .iter()
+            .map(|signature| {
+                signature
+                    .as_string()
+                    .ok_or_else(|| Error::InvalidField("signatures".to_string()))?
+                    .parse()
+                    .map_err(Error::from)
+            })
+            .collect::<Result<Vec<Signature>>>()?;
+
+        let meta = get(value, "meta")?;
+        let meta = if meta.is_object() {
+            Some(TransactionStatusMeta::try_from(&meta)?)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            slot: u64_from_jsvalue(&get(value, "slot")?, "slot")?,
+            block_time: get(value, "blockTime")?.as_f64().map(|time| time as i64),
+            transaction: VersionedTransaction {
+                signatures,
+                message,
+            },
+            meta,
+        })
+    }
+}
+
+impl TryFrom<&JsValue> for TransactionStatusMeta {
+    type Error = Error;
+
+    fn try_from(meta: &JsValue) -> Result<Self> {
+        let balances = |name: &str| -> Result<Vec<u64>> {
+            Array::from(&get(meta, name)?)
+                .iter()
+                .map(|balance| u64_from_jsvalue(&balance, name))
+                .collect()
+        };
+        let token_balances = |name: &str| -> Result<Vec<TokenBalance>> {
+            optional_array(meta, name)?
+                .iter()
+                .map(|balance| token_balance_from_jsvalue(&balance, name))
+                .collect()
+        };
+
+        let mut inner_instructions = vec![];
+        for inner in optional_array(meta, "innerInstructions")?.iter() {
+            let index = u8_field(&inner, "index")?;
+            let instructions = Array::from(&get(&inner, "instructions")?)
+                .iter()
+                .map(|instruction| compiled_instruction_from_jsvalue(&instruction))
+                .collect::<Result<Vec<_>>>()?;
+            inner_instructions.push(InnerInstructions {
+                index,
+                instructions,
+            });
+        }
+
+        let loaded_addresses = get(meta, "loadedAddresses")?;
+        let loaded_addresses = if loaded_addresses.is_object() {
+            LoadedAddresses {
+                writable: pubkeys_from_jsvalue(&get(&loaded_addresses, "writable")?)?,
+                readonly: pubkeys_from_jsvalue(&get(&loaded_addresses, "readonly")?)?,
+            }
+        } else {
+            LoadedAddresses::default()
+        };
+
+        let compute_units_consumed = get(meta, "computeUnitsConsumed")?;
+        let compute_units_consumed =
+            if compute_units_consumed.is_null() || compute_units_consumed.is_undefined() {
+                None
+            } else {
+                Some(u64_from_jsvalue(
+                    &compute_units_consumed,
+                    "computeUnitsConsumed",
+                )?)
+            };
+
+        Ok(Self {
+            err: transaction_error_from_jsvalue(&get(meta, "err")?)?,
+            fee: u64_from_jsvalue(&get(meta, "fee")?, "fee")?,
+            pre_balances: balances("preBalances")?,
+            post_balances: balances("postBalances")?,
+            inner_instructions,
+            log_messages: optional_array(meta, "logMessages")?
+                .iter()
+                .filter_map(|log| log.as_string())
+                .collect(),
+            pre_token_balances: token_balances("preTokenBalances")?,
+            post_token_balances: token_balances("postTokenBalances")?,
+            loaded_addresses,
+            compute_units_consumed,
+        })
+    }
+}
+
+fn get(value: &JsValue, name: &str) -> Result<JsValue> {
+    Ok(Reflect::get(value, &JsValue::from(name))?)
+}
+
+/// Array field that the RPC reports as `null` for older ledger history
+fn optional_array(value: &JsValue, name: &str) -> Result<Array> {
+    let array = get(value, name)?;
+    if array.is_null() || array.is_undefined() {
+        return Ok(Array::new());
+    }
+    if !array.is_array() {
+        return Err(Error::InvalidField(name.to_string()));
+    }
+    Ok(Array::from(&array))
+}
+
+fn u8_field(value: &JsValue, field: &str) -> Result<u8> {
+    let number = u64_from_jsvalue(&get(value, field)?, field)?;
+    u8::try_from(number).map_err(|_| Error::Overflow(field.to_string()))
+}
+
+/// Decode a `PublicKey` instance or a base58 string
+fn pubkey_from_jsvalue(value: &JsValue, field: &str) -> Result<Pubkey> {
+    let address = match value.as_string() {
+        Some(address) => address,
+        None => {
+            let to_base58 = Reflect::get(value, &JsValue::from("toBase58"))?;
+            if !to_base58.is_function() {
+                return Err(Error::InvalidField(field.to_string()));
+            }
+            js_sys::Function::from(to_base58)
+                .call0(value)?
+                .as_string()
+                .ok_or_else(|| Error::InvalidField(field.to_string()))?
+        }
+    };
+    Ok(address.parse()?)
+}
+
+fn pubkeys_from_jsvalue(value: &JsValue) -> Result<Vec<Pubkey>> {
+    if !value.is_array() {
+        return Ok(vec![]);
+    }
+    Array::from(value)
+        .iter()
+        .map(|address| pubkey_from_jsvalue(&address, "loadedAddresses"))
+        .collect()
+}
+
+fn optional_pubkey(value: &JsValue, field: &str) -> Result<Option<Pubkey>> {
+    let value = get(value, field)?;
+    if value.is_null() || value.is_undefined() {
+        return Ok(None);
+    }
+    Ok(Some(pubkey_from_jsvalue(&value, field)?))
+}
+
+fn compiled_instruction_from_jsvalue(value: &JsValue) -> Result<CompiledInstruction> {
+    let accounts = Array::from(&get(value, "accounts")?)
+        .iter()
+        .map(|index| {
+            let index = u64_from_jsvalue(&index, "accounts")?;
+            u8::try_from(index).map_err(|_| Error::Overflow("accounts".to_string()))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let data = get(value, "data")?
+        .as_string()
+        .ok_or_else(|| Error::InvalidField("data".to_string()))?;
+    Ok(CompiledInstruction {
+        program_id_index: u8_field(value, "programIdIndex")?,
+        accounts,
+        data: bs58::decode(data)
+            .into_vec()
+            .map_err(|_| Error::InvalidField("data".to_string()))?,
+    })
+}
+
+fn token_balance_from_jsvalue(value: &JsValue, field: &str) -> Result<TokenBalance> {
+    let ui_token_amount = get(value, "uiTokenAmount")?;
+    let amount = get(&ui_token_amount, "amount")?
+        .as_string()
+        .and_then(|amount| amount.parse().ok())
+        .ok_or_else(|| Error::InvalidField(format!("{field}.uiTokenAmount.amount")))?;
+    Ok(TokenBalance {
+        account_index: u8_field(value, "accountIndex")?,
+        mint: pubkey_from_jsvalue(&get(value, "mint")?, "mint")?,
+        owner: optional_pubkey(value, "owner")?,
+        program_id: optional_pubkey(value, "programId")?,
+        amount,
+        decimals: u8_field(&ui_token_amount, "decimals")?,
+    })
+}
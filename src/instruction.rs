@@ -3,7 +3,8 @@
 //!
 use crate::account::*;
 use crate::imports::*;
-use solana_program::instruction::Instruction;
+use crate::publickey::PublicKey;
+use solana_program::instruction::{AccountMeta as SolanaAccountMeta, Instruction};
 
 #[wasm_bindgen]
 extern "C" {
@@ -41,7 +42,7 @@ impl TransactionInstructionConfig {
 
 #[wasm_bindgen]
 extern "C" {
-    #[wasm_bindgen(js_namespace=solanaWeb3, js_name = TransactionInstruction)]
+    #[wasm_bindgen(extends = Object)]
     #[derive(Debug, Clone)]
     /// TransactionInstruction
     ///
@@ -49,12 +50,36 @@ extern "C" {
     ///
     pub type TransactionInstruction;
 
-    #[wasm_bindgen(constructor, js_namespace=["solanaWeb3"])]
+    #[wasm_bindgen(getter, method)]
+    /// Getter: Public keys to include in this transaction (`AccountMeta` list)
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/TransactionInstruction.html#keys)
+    ///
+    pub fn keys(this: &TransactionInstruction) -> Array;
+
+    #[wasm_bindgen(getter, method, js_name = "programId")]
+    /// Getter: Program Id to execute
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/TransactionInstruction.html#programId)
+    ///
+    pub fn program_id(this: &TransactionInstruction) -> PublicKey;
+
+    #[wasm_bindgen(getter, method)]
+    /// Getter: Program input
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/TransactionInstruction.html#data)
+    ///
+    pub fn data(this: &TransactionInstruction) -> Vec<u8>;
+}
+
+impl TransactionInstruction {
     /// Create TransactionInstruction
     ///
     /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/TransactionInstruction.html)
     ///
-    pub fn new(options: &TransactionInstructionConfig) -> TransactionInstruction;
+    pub fn new(options: &TransactionInstructionConfig) -> Result<TransactionInstruction> {
+        web3_construct("TransactionInstruction", &[options])
+    }
 }
 
 impl TryFrom<&Instruction> for TransactionInstruction {
@@ -71,6 +96,27 @@ impl TryFrom<&Instruction> for TransactionInstruction {
             .keys(accounts_list)
             .program_id(&instruction.program_id)?;
 
-        Ok(TransactionInstruction::new(&cfg))
+        TransactionInstruction::new(&cfg)
+    }
+}
+
+impl TryFrom<&TransactionInstruction> for Instruction {
+    type Error = crate::error::Error;
+    fn try_from(instruction: &TransactionInstruction) -> Result<Self> {
+        let mut accounts = vec![];
+        for key in instruction.keys().iter() {
+            let get = |name: &str| js_sys::Reflect::get(&key, &JsValue::from(name));
+            accounts.push(SolanaAccountMeta {
+                pubkey: PublicKey::from(get("pubkey")?).try_into()?,
+                is_signer: get("isSigner")?.is_truthy(),
+                is_writable: get("isWritable")?.is_truthy(),
+            });
+        }
+
+        Ok(Instruction {
+            program_id: instruction.program_id().try_into()?,
+            accounts,
+            data: instruction.data(),
+        })
     }
 }
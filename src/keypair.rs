@@ -0,0 +1,82 @@
+//!
+//! [`Keypair`](https://solana-labs.github.io/solana-web3.js/classes/Keypair.html) class bindings.
+//!
+
+use crate::imports::*;
+use crate::publickey::PublicKey;
+use solana_sdk::signer::keypair::Keypair as SolanaKeypair;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(extends = Object)]
+    #[derive(Debug, Clone)]
+    /// Keypair
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Keypair.html)
+    ///
+    pub type Keypair;
+
+    #[wasm_bindgen(getter, method, js_name = "publicKey")]
+    /// Getter: The public key for this keypair
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Keypair.html#publicKey)
+    ///
+    pub fn public_key(this: &Keypair) -> PublicKey;
+
+    #[wasm_bindgen(getter, method, js_name = "secretKey")]
+    /// Getter: The raw secret key for this keypair (64 bytes)
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Keypair.html#secretKey)
+    ///
+    pub fn secret_key(this: &Keypair) -> Vec<u8>;
+}
+
+impl Keypair {
+    /// Generate a new random keypair
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Keypair.html#generate)
+    ///
+    pub fn generate() -> Result<Keypair> {
+        web3_call_static("Keypair", "generate", &[])
+    }
+
+    /// Create a keypair from a raw 64-byte secret key
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Keypair.html#fromSecretKey)
+    ///
+    pub fn from_secret_key(secret_key: &[u8]) -> Result<Keypair> {
+        web3_call_static("Keypair", "fromSecretKey", &[&Uint8Array::from(secret_key)])
+    }
+
+    /// Generate a keypair from a 32-byte seed
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Keypair.html#fromSeed)
+    ///
+    pub fn from_seed(seed: &[u8; 32]) -> Result<Keypair> {
+        web3_call_static("Keypair", "fromSeed", &[&Uint8Array::from(&seed[..])])
+    }
+
+    /// Public key of this keypair as a native [`Pubkey`]
+    pub fn pubkey(&self) -> Result<Pubkey> {
+        self.public_key().try_into()
+    }
+}
+
+impl TryFrom<&SolanaKeypair> for Keypair {
+    type Error = crate::error::Error;
+
+    fn try_from(keypair: &SolanaKeypair) -> Result<Self> {
+        Keypair::from_secret_key(&keypair.to_bytes())
+    }
+}
+
+impl TryFrom<&Keypair> for SolanaKeypair {
+    type Error = crate::error::Error;
+
+    fn try_from(keypair: &Keypair) -> Result<Self> {
+        match SolanaKeypair::from_bytes(&keypair.secret_key()) {
+            Ok(keypair) => Ok(keypair),
+            Err(_) => Err(JsValue::from("Invalid keypair").into()),
+        }
+    }
+}
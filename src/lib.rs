@@ -11,16 +11,26 @@ Rust `wasm_bindgen` bindings for [Solana `web3.js` API](https://docs.solana.com/
 
 pub mod account;
 pub mod api;
+pub mod compute_budget;
 pub mod connection;
 pub mod error;
+pub mod history;
 pub mod instruction;
+pub mod keypair;
+pub mod message;
+pub mod nonce;
 pub mod options;
 pub mod publickey;
 pub mod result;
+pub mod siws;
 pub mod solana;
+pub mod subscription;
+pub mod system_program;
 pub mod transaction;
 pub mod utils;
+pub mod versioned_transaction;
 pub mod wallet;
+pub mod wallet_standard;
 
 pub mod prelude {
     //!
@@ -29,14 +39,24 @@ pub mod prelude {
     use super::*;
     pub use account::*;
     pub use api::*;
+    pub use compute_budget::*;
     pub use connection::*;
+    pub use history::*;
     pub use instruction::*;
+    pub use keypair::*;
+    pub use message::*;
+    pub use nonce::*;
     pub use options::*;
     pub use publickey::*;
+    pub use siws::*;
     pub use solana::*;
+    pub use subscription::*;
+    pub use system_program::*;
     pub use transaction::*;
     pub use utils::*;
+    pub use versioned_transaction::*;
     pub use wallet::*;
+    pub use wallet_standard::*;
 }
 
 mod imports;
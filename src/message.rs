@@ -0,0 +1,298 @@
+//!
+//! [`TransactionMessage`](https://solana-labs.github.io/solana-web3.js/classes/TransactionMessage.html),
+//! [`MessageV0`](https://solana-labs.github.io/solana-web3.js/classes/MessageV0.html) and
+//! [`AddressLookupTableAccount`](https://solana-labs.github.io/solana-web3.js/classes/AddressLookupTableAccount.html) class bindings.
+//!
+
+use crate::imports::*;
+use crate::instruction::TransactionInstruction;
+use crate::publickey::PublicKey;
+use js_sys::BigInt;
+use solana_program::address_lookup_table::AddressLookupTableAccount as SolanaAddressLookupTableAccount;
+use solana_program::hash::Hash;
+use solana_program::instruction::Instruction;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(extends = Object)]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    /// TransactionMessageArgs
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/types/TransactionMessageArgs.html)
+    ///
+    pub type TransactionMessageArgs;
+
+    #[wasm_bindgen(extends = Object)]
+    #[derive(Debug, Clone)]
+    /// TransactionMessage
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/TransactionMessage.html)
+    ///
+    pub type TransactionMessage;
+
+    #[wasm_bindgen(method, catch, js_name = "compileToV0Message")]
+    /// Compile the message into a [`MessageV0`] resolving accounts through the supplied lookup tables
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/TransactionMessage.html#compileToV0Message)
+    ///
+    pub fn compile_to_v0_message_impl(
+        this: &TransactionMessage,
+        address_lookup_table_accounts: Array,
+    ) -> Result<MessageV0>;
+
+    #[wasm_bindgen(extends = Object)]
+    #[derive(Debug, Clone)]
+    /// MessageV0
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/MessageV0.html)
+    ///
+    pub type MessageV0;
+
+    #[wasm_bindgen(method, js_name = "serialize")]
+    /// Serialize [`MessageV0`] in the wire format
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/MessageV0.html#serialize)
+    ///
+    pub fn serialize(this: &MessageV0) -> Vec<u8>;
+
+    #[wasm_bindgen(extends = Object)]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    /// AddressLookupTableAccountArgs
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/types/AddressLookupTableAccountArgs.html)
+    ///
+    pub type AddressLookupTableAccountArgs;
+
+    #[wasm_bindgen(extends = Object)]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    /// AddressLookupTableState
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/types/AddressLookupTableState.html)
+    ///
+    pub type AddressLookupTableState;
+
+    #[wasm_bindgen(getter, method)]
+    /// Getter: Addresses stored in the lookup table
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/types/AddressLookupTableState.html)
+    ///
+    pub fn addresses(this: &AddressLookupTableState) -> Array;
+
+    #[wasm_bindgen(extends = Object)]
+    #[derive(Debug, Clone)]
+    /// AddressLookupTableAccount
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/AddressLookupTableAccount.html)
+    ///
+    pub type AddressLookupTableAccount;
+
+    #[wasm_bindgen(getter, method)]
+    /// Getter: [`PublicKey`] of the lookup table account
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/AddressLookupTableAccount.html#key)
+    ///
+    pub fn key(this: &AddressLookupTableAccount) -> PublicKey;
+
+    #[wasm_bindgen(getter, method)]
+    /// Getter: Lookup table state
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/AddressLookupTableAccount.html#state)
+    ///
+    pub fn state(this: &AddressLookupTableAccount) -> AddressLookupTableState;
+
+    #[wasm_bindgen(method, js_name = "isActive")]
+    /// `true` if the lookup table has not been deactivated
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/AddressLookupTableAccount.html#isActive)
+    ///
+    pub fn is_active(this: &AddressLookupTableAccount) -> bool;
+}
+
+impl OptionsTrait for TransactionMessageArgs {}
+
+impl TransactionMessageArgs {
+    /// Set payerKey
+    pub fn payer_key(self, payer_key: &Pubkey) -> Result<Self> {
+        Ok(self.set("payerKey", pubkey_to_jsvalue(payer_key)?))
+    }
+
+    /// Set instructions
+    pub fn instructions(self, instructions: Vec<TransactionInstruction>) -> Self {
+        let list = Array::new();
+        for instruction in instructions {
+            list.push(&instruction.into());
+        }
+        self.set("instructions", list.into())
+    }
+
+    /// Set recentBlockhash
+    pub fn recent_blockhash(self, recent_blockhash: &Hash) -> Self {
+        self.set("recentBlockhash", recent_blockhash.to_string().into())
+    }
+}
+
+impl TransactionMessage {
+    /// Create TransactionMessage
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/TransactionMessage.html#constructor)
+    ///
+    pub fn new(args: &TransactionMessageArgs) -> Result<TransactionMessage> {
+        web3_construct("TransactionMessage", &[args])
+    }
+
+    /// Create [`TransactionMessage`] from native [`Instruction`]s
+    pub fn try_new(
+        payer_key: &Pubkey,
+        instructions: &[Instruction],
+        recent_blockhash: &Hash,
+    ) -> Result<Self> {
+        let mut instructions_list = vec![];
+        for instruction in instructions {
+            instructions_list.push(instruction.try_into()?);
+        }
+
+        let args = TransactionMessageArgs::new()
+            .payer_key(payer_key)?
+            .instructions(instructions_list)
+            .recent_blockhash(recent_blockhash);
+
+        TransactionMessage::new(&args)
+    }
+
+    /// Compile the message into a [`MessageV0`] resolving accounts through the supplied lookup tables
+    pub fn compile_to_v0_message(
+        &self,
+        address_lookup_table_accounts: &[AddressLookupTableAccount],
+    ) -> Result<MessageV0> {
+        let list = Array::new();
+        for account in address_lookup_table_accounts {
+            list.push(account.as_ref());
+        }
+        self.compile_to_v0_message_impl(list)
+    }
+}
+
+impl MessageV0 {
+    /// Deserialize [`MessageV0`] from the wire format
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/MessageV0.html#deserialize)
+    ///
+    pub fn deserialize(serialized_message: &[u8]) -> Result<MessageV0> {
+        web3_call_static(
+            "MessageV0",
+            "deserialize",
+            &[&Uint8Array::from(serialized_message)],
+        )
+    }
+}
+
+impl AddressLookupTableAccount {
+    /// Create AddressLookupTableAccount
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/AddressLookupTableAccount.html#constructor)
+    ///
+    pub fn new(args: &AddressLookupTableAccountArgs) -> Result<AddressLookupTableAccount> {
+        web3_construct("AddressLookupTableAccount", &[args])
+    }
+
+    /// Decode lookup table state from raw account data
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/AddressLookupTableAccount.html#deserialize)
+    ///
+    pub fn deserialize(account_data: &[u8]) -> Result<AddressLookupTableState> {
+        web3_call_static(
+            "AddressLookupTableAccount",
+            "deserialize",
+            &[&Uint8Array::from(account_data)],
+        )
+    }
+}
+
+impl OptionsTrait for AddressLookupTableAccountArgs {}
+
+impl AddressLookupTableAccountArgs {
+    /// Set key
+    pub fn key(self, key: &Pubkey) -> Result<Self> {
+        Ok(self.set("key", pubkey_to_jsvalue(key)?))
+    }
+
+    /// Set state
+    pub fn state(self, state: AddressLookupTableState) -> Self {
+        self.set("state", state.into())
+    }
+}
+
+impl OptionsTrait for AddressLookupTableState {}
+
+impl AddressLookupTableState {
+    /// Set deactivationSlot
+    pub fn deactivation_slot(self, deactivation_slot: u64) -> Self {
+        self.set("deactivationSlot", BigInt::from(deactivation_slot).into())
+    }
+
+    /// Set lastExtendedSlot
+    pub fn last_extended_slot(self, last_extended_slot: u64) -> Self {
+        self.set("lastExtendedSlot", JsValue::from(last_extended_slot as f64))
+    }
+
+    /// Set lastExtendedSlotStartIndex
+    pub fn last_extended_slot_start_index(self, index: u8) -> Self {
+        self.set("lastExtendedSlotStartIndex", JsValue::from(index))
+    }
+
+    /// Set authority
+    pub fn authority(self, authority: Option<&Pubkey>) -> Result<Self> {
+        match authority {
+            Some(authority) => Ok(self.set("authority", pubkey_to_jsvalue(authority)?)),
+            None => Ok(self.set("authority", JsValue::UNDEFINED)),
+        }
+    }
+
+    /// Set addresses
+    pub fn set_addresses(self, addresses: &[Pubkey]) -> Result<Self> {
+        let list = Array::new();
+        for address in addresses {
+            list.push(&pubkey_to_jsvalue(address)?);
+        }
+        Ok(self.set("addresses", list.into()))
+    }
+
+    /// Addresses stored in the lookup table as native [`Pubkey`]s
+    pub fn pubkeys(&self) -> Result<Vec<Pubkey>> {
+        let mut result = vec![];
+        for address in self.addresses().iter() {
+            result.push(PublicKey::from(address).try_into()?);
+        }
+        Ok(result)
+    }
+}
+
+impl TryFrom<&SolanaAddressLookupTableAccount> for AddressLookupTableAccount {
+    type Error = crate::error::Error;
+
+    fn try_from(account: &SolanaAddressLookupTableAccount) -> Result<Self> {
+        let state = AddressLookupTableState::new()
+            .deactivation_slot(u64::MAX)
+            .last_extended_slot(0)
+            .last_extended_slot_start_index(0)
+            .authority(None)?
+            .set_addresses(&account.addresses)?;
+
+        let args = AddressLookupTableAccountArgs::new()
+            .key(&account.key)?
+            .state(state);
+
+        AddressLookupTableAccount::new(&args)
+    }
+}
+
+impl TryFrom<AddressLookupTableAccount> for SolanaAddressLookupTableAccount {
+    type Error = crate::error::Error;
+
+    fn try_from(account: AddressLookupTableAccount) -> Result<Self> {
+        Ok(Self {
+            key: account.key().try_into()?,
+            addresses: account.state().pubkeys()?,
+        })
+    }
+}
@@ -0,0 +1,158 @@
+//!
+//! [`NonceAccount`](https://solana-labs.github.io/solana-web3.js/classes/NonceAccount.html) class bindings
+//! and native nonce account state decoding.
+//!
+
+use crate::error::Error;
+use crate::imports::*;
+use crate::instruction::TransactionInstruction;
+use crate::publickey::PublicKey;
+use js_sys::Reflect;
+use solana_sdk::hash::Hash;
+use solana_sdk::nonce::state::{Data, State, Versions};
+
+/// Size of the nonce account data, used to compute its rent-exempt balance
+pub const NONCE_ACCOUNT_LENGTH: usize = solana_sdk::nonce::State::size();
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(extends = Object)]
+    #[derive(Debug, Clone)]
+    /// NonceAccount
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/NonceAccount.html)
+    ///
+    pub type NonceAccount;
+
+    #[wasm_bindgen(getter, method, js_name = "authorizedPubkey")]
+    /// Getter: Authority allowed to advance the nonce
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/NonceAccount.html#authorizedPubkey)
+    ///
+    pub fn authorized_pubkey_impl(this: &NonceAccount) -> PublicKey;
+
+    #[wasm_bindgen(getter, method, js_name = "nonce")]
+    /// Getter: Stored nonce, used in place of a recent blockhash
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/NonceAccount.html#nonce)
+    ///
+    pub fn nonce_impl(this: &NonceAccount) -> JsValue;
+
+    #[wasm_bindgen(getter, method, js_name = "feeCalculator")]
+    /// Getter: Fee calculator recorded when the nonce was stored
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/NonceAccount.html#feeCalculator)
+    ///
+    pub fn fee_calculator(this: &NonceAccount) -> JsValue;
+
+    #[wasm_bindgen(extends = Object)]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    /// NonceInformation
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/types/NonceInformation.html)
+    ///
+    pub type NonceInformation;
+}
+
+impl NonceAccount {
+    /// Deserialize NonceAccount from the account data
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/NonceAccount.html#fromAccountData)
+    ///
+    pub fn from_account_data(data: &[u8]) -> Result<NonceAccount> {
+        web3_call_static(
+            "NonceAccount",
+            "fromAccountData",
+            &[&Uint8Array::from(data)],
+        )
+    }
+
+    /// Authority allowed to advance the nonce
+    pub fn authorized_pubkey(&self) -> Result<Pubkey> {
+        self.authorized_pubkey_impl().try_into()
+    }
+
+    /// Stored nonce, used in place of a recent blockhash
+    pub fn nonce(&self) -> Result<Hash> {
+        self.nonce_impl()
+            .as_string()
+            .ok_or_else(|| Error::InvalidField("nonce".to_string()))?
+            .parse()
+            .map_err(|_| Error::InvalidField("nonce".to_string()))
+    }
+
+    /// Lamports per signature recorded when the nonce was stored
+    pub fn lamports_per_signature(&self) -> Result<u64> {
+        let lamports_per_signature = Reflect::get(
+            &self.fee_calculator(),
+            &JsValue::from("lamportsPerSignature"),
+        )?;
+        u64_from_jsvalue(&lamports_per_signature, "lamportsPerSignature")
+    }
+}
+
+impl OptionsTrait for NonceInformation {}
+
+impl NonceInformation {
+    /// Set nonce
+    pub fn nonce(self, nonce: &Hash) -> Self {
+        self.set("nonce", nonce.to_string().into())
+    }
+
+    /// Set nonceInstruction
+    pub fn nonce_instruction(self, nonce_instruction: TransactionInstruction) -> Self {
+        self.set("nonceInstruction", nonce_instruction.into())
+    }
+}
+
+/// Decode the state of a nonce account natively, failing unless the account
+/// holds an initialized nonce
+pub fn nonce_data_from_account_data(data: &[u8]) -> Result<Data> {
+    if data.len() != NONCE_ACCOUNT_LENGTH {
+        return Err(Error::InvalidField("nonce account data".to_string()));
+    }
+    let versions: Versions = bincode::deserialize(data)?;
+    match versions.state() {
+        State::Initialized(data) => Ok(data.clone()),
+        State::Uninitialized => Err(Error::InvalidField("nonce".to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::fee_calculator::FeeCalculator;
+    use solana_sdk::nonce::state::DurableNonce;
+
+    fn account_data(state: State) -> Vec<u8> {
+        let mut data = bincode::serialize(&Versions::new(state)).unwrap();
+        data.resize(NONCE_ACCOUNT_LENGTH, 0);
+        data
+    }
+
+    #[test]
+    fn initialized_nonce_account_decodes() {
+        let authority = Pubkey::new_from_array([1; 32]);
+        let durable_nonce = DurableNonce::from_blockhash(&Hash::new_from_array([2; 32]));
+        let data = Data::new(authority, durable_nonce, 5000);
+
+        let decoded =
+            nonce_data_from_account_data(&account_data(State::Initialized(data.clone()))).unwrap();
+        assert_eq!(decoded, data);
+        assert_eq!(decoded.authority, authority);
+        assert_eq!(decoded.blockhash(), *durable_nonce.as_hash());
+        assert_eq!(decoded.fee_calculator, FeeCalculator::new(5000));
+    }
+
+    #[test]
+    fn uninitialized_nonce_account_is_rejected() {
+        assert!(nonce_data_from_account_data(&account_data(State::Uninitialized)).is_err());
+    }
+
+    #[test]
+    fn malformed_nonce_account_data_is_rejected() {
+        assert!(nonce_data_from_account_data(&[]).is_err());
+        assert!(nonce_data_from_account_data(&[0xff; NONCE_ACCOUNT_LENGTH]).is_err());
+        assert!(nonce_data_from_account_data(&[0; NONCE_ACCOUNT_LENGTH - 1]).is_err());
+    }
+}
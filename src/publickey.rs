@@ -6,7 +6,7 @@ use crate::imports::*;
 
 #[wasm_bindgen]
 extern "C" {
-    #[wasm_bindgen(js_namespace=solanaWeb3, js_name = PublicKey)]
+    #[wasm_bindgen(extends = Object)]
     #[derive(Debug)]
     /// PublicKey
     ///
@@ -14,33 +14,122 @@ extern "C" {
     ///
     pub type PublicKey;
 
-    #[wasm_bindgen(constructor, js_namespace=solanaWeb3)]
+    #[wasm_bindgen(method, js_name = "toBytes")]
+    /// Convert [`PublicKey`] to bytes array
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/PublicKey.html#toBytes)
+    ///
+    pub fn to_bytes(this: &PublicKey) -> Vec<u8>;
+
+    #[wasm_bindgen(method, js_name = "toString")]
+    /// Convert [`PublicKey`] to string
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/PublicKey.html#toString)
+    ///
+    pub fn to_string(this: &PublicKey) -> String;
+
+    #[wasm_bindgen(method, js_name = "toBase58")]
+    /// Return the base-58 representation of the [`PublicKey`]
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/PublicKey.html#toBase58)
+    ///
+    pub fn to_base58(this: &PublicKey) -> String;
+
+    #[wasm_bindgen(method, js_name = "equals")]
+    /// Checks if two [`PublicKey`]s are equal
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/PublicKey.html#equals)
+    ///
+    pub fn equals(this: &PublicKey, public_key: &PublicKey) -> bool;
+}
+
+impl PublicKey {
     /// Create [`PublicKey`] from sring
     ///
     /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/PublicKey.html#constructor)
     ///
-    pub fn new(str: String) -> PublicKey;
+    pub fn new(str: String) -> Result<PublicKey> {
+        web3_construct("PublicKey", &[&JsValue::from(str)])
+    }
 
-    #[wasm_bindgen(constructor, js_namespace=solanaWeb3)]
     /// Create [`PublicKey`] from bytes array
     ///
     /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/PublicKey.html#constructor)
     ///
-    pub fn new_from_array(bytes: Vec<u8>) -> PublicKey;
+    pub fn new_from_array(bytes: Vec<u8>) -> Result<PublicKey> {
+        web3_construct("PublicKey", &[&Uint8Array::from(bytes.as_slice())])
+    }
 
-    #[wasm_bindgen(method, js_name = "toBytes")]
-    /// Convert [`PublicKey`] to bytes array
+    /// Find a valid program address and its bump seed
     ///
-    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/PublicKey.html#toBytes)
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/PublicKey.html#findProgramAddressSync)
     ///
-    pub fn to_bytes(this: &PublicKey) -> Vec<u8>;
+    pub fn find_program_address(seeds: &[&[u8]], program_id: &Pubkey) -> Result<(Pubkey, u8)> {
+        let result: Array = web3_call_static(
+            "PublicKey",
+            "findProgramAddressSync",
+            &[&seeds_to_jsvalue(seeds), &pubkey_to_jsvalue(program_id)?],
+        )?;
+        let address = PublicKey::from(result.get(0)).try_into()?;
+        let bump = result
+            .get(1)
+            .as_f64()
+            .ok_or_else(|| JsValue::from("Invalid program address bump seed"))?;
+        Ok((address, bump as u8))
+    }
 
-    #[wasm_bindgen(method, js_name = "toString")]
-    /// Convert [`PublicKey`] to string
+    /// Derive a program address from seeds and a program ID; fails if the
+    /// resulting address is on the ed25519 curve
     ///
-    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/PublicKey.html#toString)
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/PublicKey.html#createProgramAddressSync)
     ///
-    pub fn to_string(this: &PublicKey) -> String;
+    pub fn create_program_address(seeds: &[&[u8]], program_id: &Pubkey) -> Result<Pubkey> {
+        let address: PublicKey = web3_call_static(
+            "PublicKey",
+            "createProgramAddressSync",
+            &[&seeds_to_jsvalue(seeds), &pubkey_to_jsvalue(program_id)?],
+        )?;
+        address.try_into()
+    }
+
+    /// Derive a public key from another key, a seed, and a program ID
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/PublicKey.html#createWithSeed)
+    ///
+    pub async fn create_with_seed(
+        base: &Pubkey,
+        seed: &str,
+        program_id: &Pubkey,
+    ) -> Result<Pubkey> {
+        let promise: js_sys::Promise = web3_call_static(
+            "PublicKey",
+            "createWithSeed",
+            &[
+                &pubkey_to_jsvalue(base)?,
+                &JsValue::from(seed),
+                &pubkey_to_jsvalue(program_id)?,
+            ],
+        )?;
+        let address = wasm_bindgen_futures::JsFuture::from(promise).await?;
+        PublicKey::from(address).try_into()
+    }
+
+    /// Check that a pubkey is on the ed25519 curve
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/PublicKey.html#isOnCurve)
+    ///
+    pub fn is_on_curve(bytes: &[u8]) -> Result<bool> {
+        let on_curve: JsValue =
+            web3_call_static("PublicKey", "isOnCurve", &[&Uint8Array::from(bytes)])?;
+        Ok(on_curve.is_truthy())
+    }
+}
+
+fn seeds_to_jsvalue(seeds: &[&[u8]]) -> Array {
+    seeds
+        .iter()
+        .map(|seed| JsValue::from(Uint8Array::from(*seed)))
+        .collect()
 }
 
 impl TryFrom<PublicKey> for Pubkey {
@@ -58,7 +147,7 @@ impl TryFrom<&Pubkey> for PublicKey {
     type Error = crate::error::Error;
 
     fn try_from(key: &Pubkey) -> Result<Self> {
-        Ok(PublicKey::new_from_array(key.to_bytes().to_vec()))
+        PublicKey::new_from_array(key.to_bytes().to_vec())
     }
 }
 
@@ -66,7 +155,7 @@ impl TryFrom<&[u8]> for PublicKey {
     type Error = crate::error::Error;
 
     fn try_from(bytes: &[u8]) -> Result<Self> {
-        Ok(PublicKey::new_from_array(bytes.to_vec()))
+        PublicKey::new_from_array(bytes.to_vec())
     }
 }
 
@@ -74,6 +163,6 @@ impl TryFrom<&str> for PublicKey {
     type Error = crate::error::Error;
 
     fn try_from(str: &str) -> Result<Self> {
-        Ok(PublicKey::new(str.to_string()))
+        PublicKey::new(str.to_string())
     }
 }
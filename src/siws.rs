@@ -0,0 +1,663 @@
+//!
+//! [Sign-In With Solana](https://github.com/phantom/sign-in-with-solana) message builder and verifier.
+//!
+//! Message construction, parsing and verification are pure Rust and can be
+//! used natively to verify sign-in requests on the backend.
+//!
+
+use crate::error::Error;
+use crate::imports::*;
+use crate::wallet::{verify_message_signature, WalletAdapterTrait};
+use crate::wallet_standard::{StandardWalletAdapter, WalletAccount, WalletFeature};
+use js_sys::Reflect;
+use solana_sdk::signature::Signature;
+
+const HEADER_SUFFIX: &str = " wants you to sign in with your Solana account:";
+
+/// Names of the single-line fields, in the order the message lists them
+const FIELD_NAMES: [&str; 8] = [
+    "URI",
+    "Version",
+    "Chain ID",
+    "Nonce",
+    "Issued At",
+    "Expiration Time",
+    "Not Before",
+    "Request ID",
+];
+
+/// Sign-In With Solana request fields (`SolanaSignInInput`)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SignInInput {
+    pub domain: Option<String>,
+    pub address: Option<String>,
+    pub statement: Option<String>,
+    pub uri: Option<String>,
+    pub version: Option<String>,
+    pub chain_id: Option<String>,
+    pub nonce: Option<String>,
+    pub issued_at: Option<String>,
+    pub expiration_time: Option<String>,
+    pub not_before: Option<String>,
+    pub request_id: Option<String>,
+    pub resources: Vec<String>,
+}
+
+/// Result of a sign-in request (`SolanaSignInOutput`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignInOutput {
+    pub pubkey: Pubkey,
+    pub signed_message: Vec<u8>,
+    pub signature: Signature,
+}
+
+impl SignInInput {
+    pub fn new(domain: &str) -> Self {
+        Self {
+            domain: Some(domain.to_string()),
+            version: Some("1".to_string()),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_address(mut self, address: &Pubkey) -> Self {
+        self.address = Some(address.to_string());
+        self
+    }
+
+    pub fn with_statement(mut self, statement: &str) -> Self {
+        self.statement = Some(statement.to_string());
+        self
+    }
+
+    pub fn with_uri(mut self, uri: &str) -> Self {
+        self.uri = Some(uri.to_string());
+        self
+    }
+
+    pub fn with_chain_id(mut self, chain_id: &str) -> Self {
+        self.chain_id = Some(chain_id.to_string());
+        self
+    }
+
+    pub fn with_nonce(mut self, nonce: &str) -> Self {
+        self.nonce = Some(nonce.to_string());
+        self
+    }
+
+    pub fn with_issued_at(mut self, issued_at: &str) -> Self {
+        self.issued_at = Some(issued_at.to_string());
+        self
+    }
+
+    pub fn with_expiration_time(mut self, expiration_time: &str) -> Self {
+        self.expiration_time = Some(expiration_time.to_string());
+        self
+    }
+
+    pub fn with_not_before(mut self, not_before: &str) -> Self {
+        self.not_before = Some(not_before.to_string());
+        self
+    }
+
+    pub fn with_request_id(mut self, request_id: &str) -> Self {
+        self.request_id = Some(request_id.to_string());
+        self
+    }
+
+    pub fn with_resources(mut self, resources: &[&str]) -> Self {
+        self.resources = resources.iter().map(|r| r.to_string()).collect();
+        self
+    }
+
+    /// Build the message text presented to the user; `domain` and `address` must be set
+    pub fn to_message(&self) -> Result<String> {
+        let domain = self
+            .domain
+            .as_ref()
+            .ok_or_else(|| Error::SignIn("missing domain".to_string()))?;
+        let address = self
+            .address
+            .as_ref()
+            .ok_or_else(|| Error::SignIn("missing address".to_string()))?;
+
+        let mut message = format!("{domain}{HEADER_SUFFIX}\n{address}");
+        if let Some(statement) = &self.statement {
+            if statement.contains('\n') {
+                return Err(Error::SignIn(
+                    "statement must not contain line breaks".to_string(),
+                ));
+            }
+            message += &format!("\n\n{statement}");
+        }
+
+        let mut fields = vec![];
+        let optional = [
+            ("URI", &self.uri),
+            ("Version", &self.version),
+            ("Chain ID", &self.chain_id),
+            ("Nonce", &self.nonce),
+            ("Issued At", &self.issued_at),
+            ("Expiration Time", &self.expiration_time),
+            ("Not Before", &self.not_before),
+            ("Request ID", &self.request_id),
+        ];
+        for (name, value) in optional {
+            if let Some(value) = value {
+                fields.push(format!("{name}: {value}"));
+            }
+        }
+        if !self.resources.is_empty() {
+            fields.push("Resources:".to_string());
+            for resource in &self.resources {
+                fields.push(format!("- {resource}"));
+            }
+        }
+        if !fields.is_empty() {
+            message += &format!("\n\n{}", fields.join("\n"));
+        } else if self.statement.as_deref().is_some_and(is_field_line) {
+            return Err(Error::SignIn(
+                "statement without fields must not look like a field".to_string(),
+            ));
+        }
+
+        Ok(message)
+    }
+
+    /// Parse a sign-in message text back into its fields.
+    ///
+    /// The message is read by position: the header and address lines, then an
+    /// optional statement and an optional field block, each preceded by a blank
+    /// line. A statement followed by a field block is never mistaken for a
+    /// field, even when its text looks like one. Without a field block, a single
+    /// section after the address is read as fields if its first line looks like
+    /// one; [`to_message`](Self::to_message) refuses to produce such messages.
+    pub fn parse(message: &str) -> Result<Self> {
+        let invalid = |reason: &str| Error::SignIn(format!("invalid message: {reason}"));
+
+        let (header, rest) = message
+            .split_once('\n')
+            .ok_or_else(|| invalid("missing address"))?;
+        let domain = header
+            .strip_suffix(HEADER_SUFFIX)
+            .ok_or_else(|| invalid("missing header"))?;
+        let (address, rest) = rest.split_once('\n').unwrap_or((rest, ""));
+
+        let mut input = SignInInput {
+            domain: Some(domain.to_string()),
+            address: Some(address.to_string()),
+            ..Default::default()
+        };
+        if rest.is_empty() {
+            return Ok(input);
+        }
+
+        let rest = rest
+            .strip_prefix('\n')
+            .ok_or_else(|| invalid("expected a blank line after the address"))?;
+        let (statement, fields) = match rest.split_once("\n\n") {
+            Some((statement, fields)) => (Some(statement), Some(fields)),
+            None if is_field_line(rest.split('\n').next().unwrap_or_default()) => {
+                (None, Some(rest))
+            }
+            None => (Some(rest), None),
+        };
+        if let Some(statement) = statement {
+            if statement.contains('\n') {
+                return Err(invalid("statement must be a single line"));
+            }
+            input.statement = Some(statement.to_string());
+        }
+        if let Some(fields) = fields {
+            input.parse_fields(fields).map_err(invalid)?;
+        }
+
+        Ok(input)
+    }
+
+    /// Read the field block; fields must appear once each, in [`FIELD_NAMES`]
+    /// order, optionally followed by the resource list
+    fn parse_fields(&mut self, block: &str) -> std::result::Result<(), &'static str> {
+        let mut lines = block.split('\n').peekable();
+        {
+            let targets = [
+                &mut self.uri,
+                &mut self.version,
+                &mut self.chain_id,
+                &mut self.nonce,
+                &mut self.issued_at,
+                &mut self.expiration_time,
+                &mut self.not_before,
+                &mut self.request_id,
+            ];
+            for (name, target) in FIELD_NAMES.into_iter().zip(targets) {
+                let value = lines.peek().and_then(|line| {
+                    line.strip_prefix(name)
+                        .and_then(|value| value.strip_prefix(": "))
+                });
+                if let Some(value) = value {
+                    *target = Some(value.to_string());
+                    lines.next();
+                }
+            }
+        }
+        if lines.peek() == Some(&"Resources:") {
+            lines.next();
+            for line in lines.by_ref() {
+                let resource = line
+                    .strip_prefix("- ")
+                    .ok_or("unexpected line after resources")?;
+                self.resources.push(resource.to_string());
+            }
+        }
+        match lines.next() {
+            Some(_) => Err("unexpected line in fields"),
+            None => Ok(()),
+        }
+    }
+
+    /// Verify that `output` was signed by its account and that the signed
+    /// message matches every field set on this request
+    pub fn verify(&self, output: &SignInOutput) -> Result<()> {
+        self.verify_signed(output).map(|_| ())
+    }
+
+    /// [`verify`](Self::verify) `output` and check the signed `Expiration Time`
+    /// and `Not Before` against `now`, in seconds since the Unix epoch
+    pub fn verify_at(&self, output: &SignInOutput, now: i64) -> Result<()> {
+        let signed = self.verify_signed(output)?;
+        let timestamp = |name: &str, value: &str| {
+            unix_timestamp(value).ok_or_else(|| Error::SignIn(format!("invalid `{name}`")))
+        };
+        if let Some(expiration_time) = &signed.expiration_time {
+            if now >= timestamp("expirationTime", expiration_time)? {
+                return Err(Error::SignIn("message has expired".to_string()));
+            }
+        }
+        if let Some(not_before) = &signed.not_before {
+            if now < timestamp("notBefore", not_before)? {
+                return Err(Error::SignIn("message is not valid yet".to_string()));
+            }
+        }
+        Ok(())
+    }
+
+    fn verify_signed(&self, output: &SignInOutput) -> Result<SignInInput> {
+        let text = std::str::from_utf8(&output.signed_message)
+            .map_err(|_| Error::SignIn("signed message is not valid UTF-8".to_string()))?;
+        let signed = SignInInput::parse(text)?;
+
+        if signed.address.as_deref() != Some(output.pubkey.to_string().as_str()) {
+            return Err(Error::SignIn(
+                "signed message address does not match the signing account".to_string(),
+            ));
+        }
+
+        let expected = [
+            ("domain", &self.domain, &signed.domain),
+            ("address", &self.address, &signed.address),
+            ("statement", &self.statement, &signed.statement),
+            ("uri", &self.uri, &signed.uri),
+            ("version", &self.version, &signed.version),
+            ("chainId", &self.chain_id, &signed.chain_id),
+            ("nonce", &self.nonce, &signed.nonce),
+            ("issuedAt", &self.issued_at, &signed.issued_at),
+            (
+                "expirationTime",
+                &self.expiration_time,
+                &signed.expiration_time,
+            ),
+            ("notBefore", &self.not_before, &signed.not_before),
+            ("requestId", &self.request_id, &signed.request_id),
+        ];
+        for (name, expected, actual) in expected {
+            if expected.is_some() && expected != actual {
+                return Err(Error::SignIn(format!(
+                    "`{name}` does not match the request"
+                )));
+            }
+        }
+        if !self.resources.is_empty() && self.resources != signed.resources {
+            return Err(Error::SignIn(
+                "`resources` do not match the request".to_string(),
+            ));
+        }
+
+        if !verify_message_signature(&output.pubkey, &output.signed_message, &output.signature) {
+            return Err(Error::SignIn("invalid signature".to_string()));
+        }
+
+        Ok(signed)
+    }
+}
+
+fn is_field_line(line: &str) -> bool {
+    line == "Resources:"
+        || FIELD_NAMES.iter().any(|name| {
+            line.strip_prefix(name)
+                .is_some_and(|value| value.starts_with(": "))
+        })
+}
+
+/// Seconds since the Unix epoch of an RFC 3339 timestamp such as
+/// `2024-01-01T00:00:00.000Z`
+fn unix_timestamp(value: &str) -> Option<i64> {
+    let (date, time) = value.split_once(['T', 't'])?;
+    let mut date = date.splitn(3, '-');
+    let year: i64 = date.next()?.parse().ok()?;
+    let month: i64 = date.next()?.parse().ok()?;
+    let day: i64 = date.next()?.parse().ok()?;
+
+    let (time, offset) = match time.strip_suffix(['Z', 'z']) {
+        Some(time) => (time, 0),
+        None => {
+            let (time, offset) = time.split_at(time.rfind(['+', '-'])?);
+            let sign = if offset.starts_with('-') { -1 } else { 1 };
+            let (hours, minutes) = offset[1..].split_once(':')?;
+            let offset = hours.parse::<i64>().ok()? * 3600 + minutes.parse::<i64>().ok()? * 60;
+            (time, sign * offset)
+        }
+    };
+    let time = time.split('.').next()?;
+    let mut time = time.splitn(3, ':');
+    let hour: i64 = time.next()?.parse().ok()?;
+    let minute: i64 = time.next()?.parse().ok()?;
+    let second: i64 = time.next()?.parse().ok()?;
+
+    if !(1..=12).contains(&month)
+        || !(1..=31).contains(&day)
+        || hour > 23
+        || minute > 59
+        || second > 60
+    {
+        return None;
+    }
+
+    // Days since 1970-01-01 in the proleptic Gregorian calendar
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146_097 + day_of_era - 719_468;
+
+    Some(days * 86_400 + hour * 3_600 + minute * 60 + second - offset)
+}
+
+impl TryFrom<&SignInInput> for JsValue {
+    type Error = Error;
+
+    fn try_from(input: &SignInInput) -> Result<Self> {
+        let obj = Object::new();
+        let fields = [
+            ("domain", &input.domain),
+            ("address", &input.address),
+            ("statement", &input.statement),
+            ("uri", &input.uri),
+            ("version", &input.version),
+            ("chainId", &input.chain_id),
+            ("nonce", &input.nonce),
+            ("issuedAt", &input.issued_at),
+            ("expirationTime", &input.expiration_time),
+            ("notBefore", &input.not_before),
+            ("requestId", &input.request_id),
+        ];
+        for (name, value) in fields {
+            if let Some(value) = value {
+                Reflect::set(&obj, &JsValue::from(name), &JsValue::from(value))?;
+            }
+        }
+        if !input.resources.is_empty() {
+            let resources = input.resources.iter().map(JsValue::from).collect::<Array>();
+            Reflect::set(&obj, &JsValue::from("resources"), &resources)?;
+        }
+        Ok(obj.into())
+    }
+}
+
+/// Sign in by building the message locally and requesting `signMessage`
+/// from any [`WalletAdapterTrait`] implementation
+pub async fn sign_in_with_message<W>(wallet: &W, input: &SignInInput) -> Result<SignInOutput>
+where
+    W: WalletAdapterTrait + ?Sized,
+{
+    if wallet.pubkey().is_none() {
+        wallet.connect().await?;
+    }
+    let pubkey = wallet
+        .pubkey()
+        .ok_or_else(|| Error::SignIn("wallet is not connected".to_string()))?;
+
+    let mut input = input.clone();
+    input.address = Some(pubkey.to_string());
+    let signed_message = input.to_message()?.into_bytes();
+    let signature = wallet.sign_message(&signed_message).await?;
+
+    Ok(SignInOutput {
+        pubkey,
+        signed_message,
+        signature,
+    })
+}
+
+impl StandardWalletAdapter {
+    /// Sign in using the wallet `solana:signIn` feature, falling back to
+    /// `solana:signMessage` when the wallet does not implement it
+    pub async fn sign_in(&self, input: &SignInInput) -> Result<SignInOutput> {
+        if !self.wallet().supports(WalletFeature::SignIn) {
+            return sign_in_with_message(self, input).await;
+        }
+
+        let outputs = self
+            .wallet()
+            .call_feature(
+                WalletFeature::SignIn,
+                "signIn",
+                &Array::of1(&JsValue::try_from(input)?),
+            )
+            .await?;
+        let output = Array::from(&outputs).get(0);
+
+        let account = WalletAccount::from(Reflect::get(&output, &JsValue::from("account"))?);
+        let signed_message = Reflect::get(&output, &JsValue::from("signedMessage"))?;
+        let signature = Reflect::get(&output, &JsValue::from("signature"))?;
+        let output = SignInOutput {
+            pubkey: Pubkey::try_from(account.public_key())
+                .map_err(|_| Error::SignIn("invalid account public key".to_string()))?,
+            signed_message: Uint8Array::new(&signed_message).to_vec(),
+            signature: signature_from_jsvalue(&signature)?,
+        };
+        self.set_account(account);
+
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::signature::{keypair_from_seed, Keypair, Signer};
+
+    fn keypair() -> Keypair {
+        keypair_from_seed(&[7; 32]).unwrap()
+    }
+
+    fn request(address: &Pubkey) -> SignInInput {
+        SignInInput::new("example.com")
+            .with_address(address)
+            .with_statement("Sign in to Example")
+            .with_uri("https://example.com/login")
+            .with_chain_id("mainnet")
+            .with_nonce("32891756")
+            .with_issued_at("2024-01-01T00:00:00.000Z")
+            .with_expiration_time("2024-01-01T00:10:00.000Z")
+            .with_not_before("2024-01-01T00:00:00.000Z")
+            .with_request_id("request-1")
+            .with_resources(&["ipfs://bafybeiemxf5abjwjbikoz4mc3a3dla6ual3jsgpdr4cjr3oz3evfyavhwq"])
+    }
+
+    fn sign(keypair: &Keypair, message: &str) -> SignInOutput {
+        SignInOutput {
+            pubkey: keypair.pubkey(),
+            signed_message: message.as_bytes().to_vec(),
+            signature: keypair.sign_message(message.as_bytes()),
+        }
+    }
+
+    #[test]
+    fn message_round_trip() {
+        let input = request(&keypair().pubkey());
+        let message = input.to_message().unwrap();
+        assert!(message.starts_with("example.com wants you to sign in with your Solana account:\n"));
+        assert_eq!(SignInInput::parse(&message).unwrap(), input);
+
+        let minimal = SignInInput {
+            version: None,
+            ..SignInInput::new("example.com").with_address(&keypair().pubkey())
+        };
+        assert_eq!(
+            SignInInput::parse(&minimal.to_message().unwrap()).unwrap(),
+            minimal
+        );
+
+        let fields_only = SignInInput::new("example.com")
+            .with_address(&keypair().pubkey())
+            .with_nonce("1");
+        assert_eq!(
+            SignInInput::parse(&fields_only.to_message().unwrap()).unwrap(),
+            fields_only
+        );
+    }
+
+    #[test]
+    fn statement_before_fields_is_not_read_as_a_field() {
+        let input = request(&keypair().pubkey()).with_statement("Nonce: evil");
+        let parsed = SignInInput::parse(&input.to_message().unwrap()).unwrap();
+        assert_eq!(parsed.statement.as_deref(), Some("Nonce: evil"));
+        assert_eq!(parsed.nonce.as_deref(), Some("32891756"));
+        assert_eq!(parsed, input);
+
+        assert!(request(&keypair().pubkey())
+            .with_statement("line\nNonce: evil")
+            .to_message()
+            .is_err());
+    }
+
+    #[test]
+    fn statement_without_fields_that_looks_like_a_field() {
+        let address = keypair().pubkey();
+        let message = format!("example.com{HEADER_SUFFIX}\n{address}\n\nNonce: x");
+        let parsed = SignInInput::parse(&message).unwrap();
+        assert_eq!(parsed.statement, None);
+        assert_eq!(parsed.nonce.as_deref(), Some("x"));
+
+        let input = SignInInput {
+            version: None,
+            ..SignInInput::new("example.com")
+                .with_address(&address)
+                .with_statement("Nonce: x")
+        };
+        assert!(input.to_message().is_err());
+
+        let input = SignInInput {
+            version: None,
+            ..SignInInput::new("example.com")
+                .with_address(&address)
+                .with_statement("Nonce x")
+        };
+        assert_eq!(
+            SignInInput::parse(&input.to_message().unwrap()).unwrap(),
+            input
+        );
+    }
+
+    #[test]
+    fn malformed_field_blocks_are_rejected() {
+        let header = format!("example.com{HEADER_SUFFIX}\n{}", keypair().pubkey());
+        for fields in [
+            "Nonce: 1\nNonce: 2",
+            "Nonce: 1\nURI: https://example.com",
+            "Nonce: 1\nUnknown: 2",
+            "Resources:\n- a\nNonce: 1",
+        ] {
+            let message = format!("{header}\n\nStatement\n\n{fields}");
+            assert!(SignInInput::parse(&message).is_err(), "{fields}");
+        }
+        assert!(SignInInput::parse(&format!("{header}\nNonce: 1")).is_err());
+    }
+
+    #[test]
+    fn verify_accepts_a_valid_signature() {
+        let keypair = keypair();
+        let input = request(&keypair.pubkey());
+        let output = sign(&keypair, &input.to_message().unwrap());
+        input.verify(&output).unwrap();
+        input
+            .verify_at(&output, unix_timestamp("2024-01-01T00:05:00Z").unwrap())
+            .unwrap();
+    }
+
+    #[test]
+    fn verify_rejects_tampered_signature_and_message() {
+        let keypair = keypair();
+        let input = request(&keypair.pubkey());
+        let message = input.to_message().unwrap();
+
+        let mut output = sign(&keypair, &message);
+        output.signature = keypair.sign_message(b"something else");
+        assert!(input.verify(&output).is_err());
+
+        let mut output = sign(&keypair, &message);
+        output.signed_message = message.replace("32891756", "32891757").into_bytes();
+        assert!(input.verify(&output).is_err());
+
+        let other = keypair_from_seed(&[8; 32]).unwrap();
+        let mut output = sign(&other, &message);
+        output.pubkey = other.pubkey();
+        assert!(input.verify(&output).is_err());
+    }
+
+    #[test]
+    fn verify_rejects_wrong_domain() {
+        let keypair = keypair();
+        let input = request(&keypair.pubkey());
+        let signed = SignInInput {
+            domain: Some("evil.example".to_string()),
+            ..input.clone()
+        };
+        let output = sign(&keypair, &signed.to_message().unwrap());
+        assert!(input.verify(&output).is_err());
+    }
+
+    #[test]
+    fn verify_at_checks_validity_window() {
+        let keypair = keypair();
+        let input = request(&keypair.pubkey());
+        let output = sign(&keypair, &input.to_message().unwrap());
+
+        let expired = unix_timestamp("2024-01-01T00:10:00Z").unwrap();
+        assert!(input.verify_at(&output, expired).is_err());
+        let early = unix_timestamp("2023-12-31T23:59:59Z").unwrap();
+        assert!(input.verify_at(&output, early).is_err());
+    }
+
+    #[test]
+    fn unix_timestamp_parses_rfc3339() {
+        assert_eq!(unix_timestamp("1970-01-01T00:00:00Z"), Some(0));
+        assert_eq!(
+            unix_timestamp("2024-01-01T00:00:00.000Z"),
+            Some(1_704_067_200)
+        );
+        assert_eq!(
+            unix_timestamp("2024-01-01T02:00:00+02:00"),
+            Some(1_704_067_200)
+        );
+        assert_eq!(
+            unix_timestamp("2023-12-31T23:00:00-01:00"),
+            Some(1_704_067_200)
+        );
+        assert_eq!(unix_timestamp("2024-02-29T00:00:00Z"), Some(1_709_164_800));
+        assert_eq!(unix_timestamp("2024-13-01T00:00:00Z"), None);
+        assert_eq!(unix_timestamp("yesterday"), None);
+    }
+}
@@ -2,28 +2,68 @@
 //! `solana-web3-sys` initialization hooks.
 //!
 
+use crate::error::Error;
 use crate::imports::*;
+use std::cell::OnceCell;
 
-static mut SOLANA_WEB3JS_GLOBAL: Option<JsValue> = None;
+thread_local! {
+    static SOLANA_WEB3JS_GLOBAL: OnceCell<JsValue> = const { OnceCell::new() };
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(catch, js_name = require)]
+    fn node_require(module: &str) -> std::result::Result<JsValue, JsValue>;
+}
+
+/// Store the web3.js module once; initializing again with the same module is
+/// a no-op and with a different one is an error, so objects already built
+/// from the stored constructors stay valid
+fn init(solana_web3js_global: &JsValue) -> JsResult<()> {
+    SOLANA_WEB3JS_GLOBAL.with(|cell| match cell.get() {
+        Some(existing) if existing != solana_web3js_global => Err(JsValue::from(
+            "solana-web3-sys is already initialized with a different web3.js module",
+        )),
+        Some(_) => Ok(()),
+        None => {
+            let _ = cell.set(solana_web3js_global.clone());
+            Ok(())
+        }
+    })
+}
 
 cfg_if! {
     if #[cfg(feature = "init")] {
         #[wasm_bindgen]
         pub fn init_solana_web3_sys(solana_web3js_global: &JsValue) -> JsResult<()> {
-            unsafe { SOLANA_WEB3JS_GLOBAL = Some(solana_web3js_global.clone()) };
-            Ok(())
+            init(solana_web3js_global)
         }
     } else {
         pub fn init_solana_web3_sys(solana_web3js_global: &JsValue) -> JsResult<()> {
-            unsafe { SOLANA_WEB3JS_GLOBAL = Some(solana_web3js_global.clone()) };
-            Ok(())
+            init(solana_web3js_global)
         }
     }
 }
 
+/// Locate web3.js when [`init_solana_web3_sys`] was not called, trying
+/// `globalThis.solanaWeb3` and then Node's `require("@solana/web3.js")`.
+fn detect() -> Option<JsValue> {
+    let global = js_sys::Reflect::get(&js_sys::global(), &JsValue::from("solanaWeb3")).ok();
+    if let Some(global) = global.filter(|global| global.is_object()) {
+        return Some(global);
+    }
+    node_require("@solana/web3.js")
+        .ok()
+        .filter(|module| module.is_object())
+}
+
+/// The web3.js module passed to [`init_solana_web3_sys`] or auto-detected on first use.
+/// Once a module is auto-detected, [`init_solana_web3_sys`] only accepts that same module.
 pub fn solana() -> Result<JsValue> {
-    let solana_web3js_global = unsafe { SOLANA_WEB3JS_GLOBAL.as_ref() }
-        .expect("SOLANA_WEB3JS_GLOBAL is not initialized; please use `solana::init_solana_web3_sys()` to initialize.")
-        .clone();
+    if let Some(solana_web3js_global) = SOLANA_WEB3JS_GLOBAL.with(|cell| cell.get().cloned()) {
+        return Ok(solana_web3js_global);
+    }
+    let solana_web3js_global = detect().ok_or(Error::NotInitialized)?;
+    init(&solana_web3js_global)?;
     Ok(solana_web3js_global)
 }
@@ -0,0 +1,223 @@
+//!
+//! [`Connection`] subscriptions (`onAccountChange`, `onLogs` etc.) exposed as [`Stream`]s.
+//!
+
+use crate::connection::Connection;
+use crate::imports::*;
+use futures::channel::mpsc::{unbounded, UnboundedReceiver};
+use futures::stream::{Stream, StreamExt};
+use solana_sdk::signature::Signature;
+use solana_sdk::slot_history::Slot;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// JavaScript callback receiving the notification value and its context.
+pub type SubscriptionCallback = Closure<dyn FnMut(JsValue, JsValue)>;
+
+/// Listener kind, used to select the matching `remove*Listener` call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListenerKind {
+    AccountChange,
+    ProgramAccountChange,
+    Logs,
+    SlotChange,
+    Signature,
+    RootChange,
+}
+
+impl ListenerKind {
+    /// `true` if web3.js removes the listener by itself after the first notification
+    fn is_once(&self) -> bool {
+        matches!(self, ListenerKind::Signature)
+    }
+
+    async fn remove(&self, connection: &Connection, id: u32) -> Result<JsValue> {
+        match self {
+            ListenerKind::AccountChange => connection.remove_account_change_listener(id).await,
+            ListenerKind::ProgramAccountChange => {
+                connection.remove_program_account_change_listener(id).await
+            }
+            ListenerKind::Logs => connection.remove_on_logs_listener(id).await,
+            ListenerKind::SlotChange => connection.remove_slot_change_listener(id).await,
+            ListenerKind::Signature => connection.remove_signature_listener(id).await,
+            ListenerKind::RootChange => connection.remove_root_change_listener(id).await,
+        }
+    }
+}
+
+/// [`Stream`] of notifications produced by a [`Connection`] listener.
+///
+/// The listener is removed from the [`Connection`] when the stream is dropped.
+pub struct Subscription<T> {
+    connection: Connection,
+    kind: ListenerKind,
+    id: Option<u32>,
+    callback: Option<SubscriptionCallback>,
+    receiver: UnboundedReceiver<Result<T>>,
+    done: bool,
+}
+
+impl<T: 'static> Subscription<T> {
+    pub(crate) fn try_new<C, S>(
+        connection: &Connection,
+        kind: ListenerKind,
+        convert: C,
+        subscribe: S,
+    ) -> Result<Self>
+    where
+        C: Fn(JsValue, JsValue) -> Result<T> + 'static,
+        S: FnOnce(&SubscriptionCallback) -> Result<u32>,
+    {
+        let (sender, receiver) = unbounded();
+        let callback = Closure::new(move |value: JsValue, context: JsValue| {
+            let _ = sender.unbounded_send(convert(value, context));
+        });
+        let id = subscribe(&callback)?;
+
+        Ok(Self {
+            connection: connection.clone(),
+            kind,
+            id: Some(id),
+            callback: Some(callback),
+            receiver,
+            done: false,
+        })
+    }
+
+    /// Listener kind
+    pub fn kind(&self) -> ListenerKind {
+        self.kind
+    }
+
+    /// Client subscription id returned by web3.js
+    pub fn id(&self) -> Option<u32> {
+        self.id
+    }
+}
+
+impl<T> Stream for Subscription<T> {
+    type Item = Result<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if self.done {
+            return Poll::Ready(None);
+        }
+        let item = self.receiver.poll_next_unpin(cx);
+        if let Poll::Ready(Some(_)) = &item {
+            if self.kind.is_once() {
+                self.done = true;
+                self.id = None;
+            }
+        }
+        item
+    }
+}
+
+impl<T> Drop for Subscription<T> {
+    fn drop(&mut self) {
+        let callback = self.callback.take();
+        if let Some(id) = self.id.take() {
+            let connection = self.connection.clone();
+            let kind = self.kind;
+            wasm_bindgen_futures::spawn_local(async move {
+                let _ = kind.remove(&connection, id).await;
+                // keep the callback alive until web3.js has released it
+                drop(callback);
+            });
+        }
+    }
+}
+
+/// Filter for [`Connection::on_logs`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LogsFilter {
+    /// All transactions except simple vote transactions
+    All,
+    /// All transactions including simple vote transactions
+    AllWithVotes,
+    /// Transactions that mention the given account
+    Mentions(Pubkey),
+}
+
+impl TryFrom<&LogsFilter> for JsValue {
+    type Error = crate::error::Error;
+
+    fn try_from(filter: &LogsFilter) -> Result<Self> {
+        match filter {
+            LogsFilter::All => Ok("all".into()),
+            LogsFilter::AllWithVotes => Ok("allWithVotes".into()),
+            LogsFilter::Mentions(pubkey) => pubkey_to_jsvalue(pubkey),
+        }
+    }
+}
+
+/// Notification produced by [`Connection::on_logs`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Logs {
+    pub signature: Signature,
+    pub err: Option<TransactionStatusError>,
+    pub logs: Vec<String>,
+}
+
+impl TryFrom<JsValue> for Logs {
+    type Error = crate::error::Error;
+
+    fn try_from(value: JsValue) -> Result<Self> {
+        let signature = js_sys::Reflect::get(&value, &JsValue::from("signature"))?
+            .as_string()
+            .ok_or_else(|| JsValue::from("Invalid logs notification signature"))?;
+        let err = js_sys::Reflect::get(&value, &JsValue::from("err"))?;
+        let logs = js_sys::Reflect::get(&value, &JsValue::from("logs"))?;
+        Ok(Self {
+            signature: signature.parse()?,
+            err: transaction_error_from_jsvalue(&err)?,
+            logs: Array::from(&logs)
+                .iter()
+                .filter_map(|log| log.as_string())
+                .collect(),
+        })
+    }
+}
+
+/// Notification produced by [`Connection::on_slot_change`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SlotInfo {
+    pub slot: Slot,
+    pub parent: Slot,
+    pub root: Slot,
+}
+
+impl TryFrom<JsValue> for SlotInfo {
+    type Error = crate::error::Error;
+
+    fn try_from(value: JsValue) -> Result<Self> {
+        let get = |name: &str| -> Result<Slot> {
+            js_sys::Reflect::get(&value, &JsValue::from(name))?
+                .as_f64()
+                .map(|v| v as Slot)
+                .ok_or_else(|| JsValue::from(format!("Invalid slot notification `{name}`")).into())
+        };
+        Ok(Self {
+            slot: get("slot")?,
+            parent: get("parent")?,
+            root: get("root")?,
+        })
+    }
+}
+
+/// Notification produced by [`Connection::on_signature`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignatureResult {
+    pub err: Option<TransactionStatusError>,
+}
+
+impl TryFrom<JsValue> for SignatureResult {
+    type Error = crate::error::Error;
+
+    fn try_from(value: JsValue) -> Result<Self> {
+        let err = js_sys::Reflect::get(&value, &JsValue::from("err"))?;
+        Ok(Self {
+            err: transaction_error_from_jsvalue(&err)?,
+        })
+    }
+}
@@ -0,0 +1,217 @@
+//!
+//! [`SystemProgram`](https://solana-labs.github.io/solana-web3.js/classes/SystemProgram.html) class bindings.
+//!
+
+use crate::imports::*;
+use crate::instruction::TransactionInstruction;
+use crate::transaction::Transaction;
+use js_sys::{BigInt, Reflect};
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(extends = Object)]
+    #[derive(Debug, Clone)]
+    /// SystemProgram
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/SystemProgram.html)
+    ///
+    pub type SystemProgram;
+}
+
+impl SystemProgram {
+    /// Generate a transaction instruction that transfers lamports from one account to another
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/SystemProgram.html#transfer)
+    ///
+    pub fn transfer(
+        from_pubkey: &Pubkey,
+        to_pubkey: &Pubkey,
+        lamports: u64,
+    ) -> Result<TransactionInstruction> {
+        let params = params(&[
+            ("fromPubkey", pubkey_to_jsvalue(from_pubkey)?),
+            ("toPubkey", pubkey_to_jsvalue(to_pubkey)?),
+            ("lamports", BigInt::from(lamports).into()),
+        ])?;
+        web3_call_static("SystemProgram", "transfer", &[&params])
+    }
+
+    /// Generate a transaction instruction that creates a new account
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/SystemProgram.html#createAccount)
+    ///
+    pub fn create_account(
+        from_pubkey: &Pubkey,
+        new_account_pubkey: &Pubkey,
+        lamports: u64,
+        space: u64,
+        program_id: &Pubkey,
+    ) -> Result<TransactionInstruction> {
+        let params = params(&[
+            ("fromPubkey", pubkey_to_jsvalue(from_pubkey)?),
+            ("newAccountPubkey", pubkey_to_jsvalue(new_account_pubkey)?),
+            ("lamports", number(lamports, "lamports")?),
+            ("space", number(space, "space")?),
+            ("programId", pubkey_to_jsvalue(program_id)?),
+        ])?;
+        web3_call_static("SystemProgram", "createAccount", &[&params])
+    }
+
+    /// Generate a transaction instruction that creates a new account at an address
+    /// generated with `from`, a seed, and `program_id`
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/SystemProgram.html#createAccountWithSeed)
+    ///
+    pub fn create_account_with_seed(
+        from_pubkey: &Pubkey,
+        new_account_pubkey: &Pubkey,
+        base_pubkey: &Pubkey,
+        seed: &str,
+        lamports: u64,
+        space: u64,
+        program_id: &Pubkey,
+    ) -> Result<TransactionInstruction> {
+        let params = params(&[
+            ("fromPubkey", pubkey_to_jsvalue(from_pubkey)?),
+            ("newAccountPubkey", pubkey_to_jsvalue(new_account_pubkey)?),
+            ("basePubkey", pubkey_to_jsvalue(base_pubkey)?),
+            ("seed", seed.into()),
+            ("lamports", number(lamports, "lamports")?),
+            ("space", number(space, "space")?),
+            ("programId", pubkey_to_jsvalue(program_id)?),
+        ])?;
+        web3_call_static("SystemProgram", "createAccountWithSeed", &[&params])
+    }
+
+    /// Generate a transaction instruction that allocates space in an account without funding
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/SystemProgram.html#allocate)
+    ///
+    pub fn allocate(account_pubkey: &Pubkey, space: u64) -> Result<TransactionInstruction> {
+        let params = params(&[
+            ("accountPubkey", pubkey_to_jsvalue(account_pubkey)?),
+            ("space", number(space, "space")?),
+        ])?;
+        web3_call_static("SystemProgram", "allocate", &[&params])
+    }
+
+    /// Generate a transaction instruction that assigns an account to a program
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/SystemProgram.html#assign)
+    ///
+    pub fn assign(account_pubkey: &Pubkey, program_id: &Pubkey) -> Result<TransactionInstruction> {
+        let params = params(&[
+            ("accountPubkey", pubkey_to_jsvalue(account_pubkey)?),
+            ("programId", pubkey_to_jsvalue(program_id)?),
+        ])?;
+        web3_call_static("SystemProgram", "assign", &[&params])
+    }
+
+    /// Generate a transaction that creates and initializes a nonce account;
+    /// `lamports` should cover the rent exemption of
+    /// [`NONCE_ACCOUNT_LENGTH`](crate::nonce::NONCE_ACCOUNT_LENGTH) bytes
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/SystemProgram.html#createNonceAccount)
+    ///
+    pub fn create_nonce_account(
+        from_pubkey: &Pubkey,
+        nonce_pubkey: &Pubkey,
+        authorized_pubkey: &Pubkey,
+        lamports: u64,
+    ) -> Result<Transaction> {
+        let params = params(&[
+            ("fromPubkey", pubkey_to_jsvalue(from_pubkey)?),
+            ("noncePubkey", pubkey_to_jsvalue(nonce_pubkey)?),
+            ("authorizedPubkey", pubkey_to_jsvalue(authorized_pubkey)?),
+            ("lamports", number(lamports, "lamports")?),
+        ])?;
+        web3_call_static("SystemProgram", "createNonceAccount", &[&params])
+    }
+
+    /// Generate an instruction to initialize a Nonce account
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/SystemProgram.html#nonceInitialize)
+    ///
+    pub fn nonce_initialize(
+        nonce_pubkey: &Pubkey,
+        authorized_pubkey: &Pubkey,
+    ) -> Result<TransactionInstruction> {
+        let params = params(&[
+            ("noncePubkey", pubkey_to_jsvalue(nonce_pubkey)?),
+            ("authorizedPubkey", pubkey_to_jsvalue(authorized_pubkey)?),
+        ])?;
+        web3_call_static("SystemProgram", "nonceInitialize", &[&params])
+    }
+
+    /// Generate an instruction to advance the nonce in a Nonce account
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/SystemProgram.html#nonceAdvance)
+    ///
+    pub fn nonce_advance(
+        nonce_pubkey: &Pubkey,
+        authorized_pubkey: &Pubkey,
+    ) -> Result<TransactionInstruction> {
+        let params = params(&[
+            ("noncePubkey", pubkey_to_jsvalue(nonce_pubkey)?),
+            ("authorizedPubkey", pubkey_to_jsvalue(authorized_pubkey)?),
+        ])?;
+        web3_call_static("SystemProgram", "nonceAdvance", &[&params])
+    }
+
+    /// Generate a transaction instruction that withdraws lamports from a Nonce account
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/SystemProgram.html#nonceWithdraw)
+    ///
+    pub fn nonce_withdraw(
+        nonce_pubkey: &Pubkey,
+        authorized_pubkey: &Pubkey,
+        to_pubkey: &Pubkey,
+        lamports: u64,
+    ) -> Result<TransactionInstruction> {
+        let params = params(&[
+            ("noncePubkey", pubkey_to_jsvalue(nonce_pubkey)?),
+            ("authorizedPubkey", pubkey_to_jsvalue(authorized_pubkey)?),
+            ("toPubkey", pubkey_to_jsvalue(to_pubkey)?),
+            ("lamports", number(lamports, "lamports")?),
+        ])?;
+        web3_call_static("SystemProgram", "nonceWithdraw", &[&params])
+    }
+
+    /// Generate a transaction instruction that authorizes a new PublicKey as the authority
+    /// on a Nonce account
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/SystemProgram.html#nonceAuthorize)
+    ///
+    pub fn nonce_authorize(
+        nonce_pubkey: &Pubkey,
+        authorized_pubkey: &Pubkey,
+        new_authorized_pubkey: &Pubkey,
+    ) -> Result<TransactionInstruction> {
+        let params = params(&[
+            ("noncePubkey", pubkey_to_jsvalue(nonce_pubkey)?),
+            ("authorizedPubkey", pubkey_to_jsvalue(authorized_pubkey)?),
+            (
+                "newAuthorizedPubkey",
+                pubkey_to_jsvalue(new_authorized_pubkey)?,
+            ),
+        ])?;
+        web3_call_static("SystemProgram", "nonceAuthorize", &[&params])
+    }
+}
+
+fn params(fields: &[(&str, JsValue)]) -> Result<JsValue> {
+    let obj = Object::new();
+    for (name, value) in fields {
+        Reflect::set(&obj, &JsValue::from(*name), value)?;
+    }
+    Ok(obj.into())
+}
+
+/// web3.js encodes these fields from a `number`, so values above
+/// `Number.MAX_SAFE_INTEGER` cannot be represented exactly
+fn number(value: u64, field: &str) -> Result<JsValue> {
+    if value as f64 > MAX_SAFE_INTEGER {
+        return Err(crate::error::Error::Overflow(field.to_string()));
+    }
+    Ok(JsValue::from(value as f64))
+}
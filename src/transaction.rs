@@ -2,12 +2,26 @@
 //! [`Transaction`](https://solana-labs.github.io/solana-web3.js/classes/Transaction.html) class bindings.
 //!
 
+use crate::api::SimulateTransactionConfig;
+use crate::compute_budget::{
+    fee_percentile, instructions_to_array, ComputeBudgetProgram, PriorityFee, PriorityFeeConfig,
+    MAX_COMPUTE_UNIT_LIMIT, MAX_PRIORITIZATION_FEE_ACCOUNTS,
+};
+use crate::connection::{Connection, LatestBlockhashInfo};
+use crate::error::{Error, JsErrorInfo};
 use crate::imports::*;
 use crate::instruction::TransactionInstruction;
+use crate::keypair::Keypair;
+use crate::nonce::{NonceAccount, NonceInformation};
+use crate::system_program::SystemProgram;
+use js_sys::Reflect;
+use solana_program::instruction::Instruction;
+use solana_sdk::signature::Signature;
+use solana_sdk::transaction::Transaction as SolanaTransaction;
 
 #[wasm_bindgen]
 extern "C" {
-    #[wasm_bindgen(js_namespace=solanaWeb3, js_name = Transaction)]
+    #[wasm_bindgen(extends = Object)]
     #[derive(Debug, Clone)]
     /// Transaction
     ///
@@ -15,41 +29,109 @@ extern "C" {
     ///
     pub type Transaction;
 
-    #[wasm_bindgen(constructor, js_namespace=["solanaWeb3"])]
-    /// Construct an empty Transaction
-    ///
-    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Transaction.html)
-    ///
-    pub fn new() -> Transaction;
-
-    #[wasm_bindgen(setter, method, js_namespace=["solanaWeb3"], js_name="feePayer")]
+    #[wasm_bindgen(setter, method, js_name = "feePayer")]
     /// Set the transaction fee payer
     ///
     /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Transaction.html#feePayer)
     ///
     pub fn set_fee_payer(this: &Transaction, fee_payer_pubkey: JsValue);
 
-    #[wasm_bindgen(setter, method, js_namespace=["solanaWeb3"], js_name="recentBlockhash")]
+    #[wasm_bindgen(setter, method, js_name = "recentBlockhash")]
     /// A recent transaction id. Must be populated by the caller
     ///
     /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Transaction.html#recentBlockhash)
     ///
     pub fn set_recent_block_hash(this: &Transaction, recent_blockhash: JsValue);
 
-    #[wasm_bindgen(method, js_namespace=["solanaWeb3"], js_name="add")]
+    #[wasm_bindgen(setter, method, js_name = "lastValidBlockHeight")]
+    /// The last block height at which the recent blockhash is valid
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Transaction.html#lastValidBlockHeight)
+    ///
+    pub fn set_last_valid_block_height(this: &Transaction, last_valid_block_height: f64);
+
+    #[wasm_bindgen(getter, method)]
+    /// The instructions to atomically execute
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Transaction.html#instructions)
+    ///
+    pub fn instructions(this: &Transaction) -> Array;
+
+    #[wasm_bindgen(setter, method, js_name = "instructions")]
+    /// The instructions to atomically execute
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Transaction.html#instructions)
+    ///
+    pub fn set_instructions(this: &Transaction, instructions: Array);
+
+    #[wasm_bindgen(getter, method, js_name = "nonceInfo")]
+    /// Getter: Durable nonce information, `None` unless the Transaction uses a nonce
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Transaction.html#nonceInfo)
+    ///
+    pub fn nonce_info(this: &Transaction) -> Option<NonceInformation>;
+
+    #[wasm_bindgen(setter, method, js_name = "nonceInfo")]
+    /// Setter: Durable nonce information
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Transaction.html#nonceInfo)
+    ///
+    pub fn set_nonce_info(this: &Transaction, nonce_info: Option<NonceInformation>);
+
+    #[wasm_bindgen(method, js_name = "add")]
     /// Add one instruction to this Transaction
     ///
     /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Transaction.html#add)
     ///
     pub fn add(this: &Transaction, instruction: TransactionInstruction);
 
-    #[wasm_bindgen(method, js_namespace=["solanaWeb3"], js_name="serialize")]
+    #[wasm_bindgen(method, catch, variadic, js_name = "sign")]
+    /// Sign the Transaction with the specified signers, replacing any existing signatures
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Transaction.html#sign)
+    ///
+    pub fn sign_impl(this: &Transaction, signers: Box<[JsValue]>) -> Result<()>;
+
+    #[wasm_bindgen(method, catch, variadic, js_name = "partialSign")]
+    /// Partially sign the Transaction with the specified signers, keeping existing signatures
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Transaction.html#partialSign)
+    ///
+    pub fn partial_sign_impl(this: &Transaction, signers: Box<[JsValue]>) -> Result<()>;
+
+    #[wasm_bindgen(method, catch, js_name = "addSignature")]
+    /// Add an externally created signature to the Transaction
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Transaction.html#addSignature)
+    ///
+    pub fn add_signature_impl(
+        this: &Transaction,
+        pubkey: JsValue,
+        signature: Uint8Array,
+    ) -> Result<()>;
+
+    #[wasm_bindgen(method, catch, js_name = "compileMessage")]
+    /// Compile the Transaction into a legacy `Message`; `feePayer` and
+    /// `recentBlockhash` must be set
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Transaction.html#compileMessage)
+    ///
+    pub fn compile_message(this: &Transaction) -> Result<JsValue>;
+
+    #[wasm_bindgen(method, js_name = "serialize")]
     /// Serialize the Transaction in the wire format.
     ///
     /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Transaction.html#serialize)
     ///
     pub fn serialize(this: &Transaction, config: SerializeConfig) -> JsValue;
 
+    #[wasm_bindgen(method, catch, js_name = "serialize")]
+    /// Serialize the Transaction in the wire format.
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Transaction.html#serialize)
+    ///
+    pub fn try_serialize(this: &Transaction, config: SerializeConfig) -> Result<JsValue>;
+
     #[wasm_bindgen(extends = Object)]
     #[derive(Debug, Clone, PartialEq, Eq)]
     /// Configuration object for Transaction.serialize()
@@ -73,6 +155,219 @@ extern "C" {
     pub fn set_verify_signatures(this: &SerializeConfig, verify_signatures: bool);
 }
 
-impl Transaction {}
+impl Transaction {
+    /// Construct an empty Transaction
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Transaction.html)
+    ///
+    pub fn new() -> Result<Transaction> {
+        web3_construct("Transaction", &[])
+    }
+
+    /// Parse a wire transaction into a Transaction object.
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Transaction.html#from)
+    ///
+    pub fn from_bytes(buffer: &[u8]) -> Result<Transaction> {
+        web3_call_static("Transaction", "from", &[&Uint8Array::from(buffer)])
+    }
+
+    /// Construct a durable nonce Transaction. The stored nonce replaces the
+    /// recent blockhash and `nonceAdvance` is always compiled as the first
+    /// instruction, so the Transaction stays valid until the nonce is advanced.
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/Transaction.html#nonceInfo)
+    ///
+    pub fn new_with_nonce(
+        fee_payer: &Pubkey,
+        nonce_pubkey: &Pubkey,
+        nonce_account: &NonceAccount,
+    ) -> Result<Transaction> {
+        let nonce_info = NonceInformation::new()
+            .nonce(&nonce_account.nonce()?)
+            .nonce_instruction(SystemProgram::nonce_advance(
+                nonce_pubkey,
+                &nonce_account.authorized_pubkey()?,
+            )?);
+        let options = Object::new();
+        Reflect::set(
+            &options,
+            &JsValue::from("feePayer"),
+            &pubkey_to_jsvalue(fee_payer)?,
+        )?;
+        Reflect::set(&options, &JsValue::from("nonceInfo"), &nonce_info)?;
+        web3_construct("Transaction", &[&options])
+    }
+
+    /// Sign the Transaction with `signers`; the first signer pays the fee
+    /// unless `feePayer` is already set. Existing signatures are discarded.
+    pub fn sign(&self, signers: &[&Keypair]) -> Result<()> {
+        self.sign_impl(signers_to_jsvalues(signers))
+    }
+
+    /// Add signatures of `signers` while keeping the existing ones, so the
+    /// Transaction can be passed on to a wallet or other co-signers
+    pub fn partial_sign(&self, signers: &[&Keypair]) -> Result<()> {
+        self.partial_sign_impl(signers_to_jsvalues(signers))
+    }
+
+    /// Add a signature produced outside of the Transaction by `pubkey`
+    pub fn add_signature(&self, pubkey: &Pubkey, signature: &Signature) -> Result<()> {
+        self.add_signature_impl(
+            pubkey_to_jsvalue(pubkey)?,
+            Uint8Array::from(signature.as_ref()),
+        )
+    }
+
+    /// Set `recentBlockhash` and `lastValidBlockHeight` from the result of
+    /// [`Connection::get_latest_block_hash`](crate::connection::Connection::get_latest_block_hash)
+    pub fn set_latest_blockhash(&self, latest_blockhash: &LatestBlockhashInfo) -> Result<()> {
+        self.set_recent_block_hash(latest_blockhash.block_hash()?.to_string().into());
+        self.set_last_valid_block_height(latest_blockhash.last_valid_block_height()? as f64);
+        Ok(())
+    }
+
+    /// Size the compute budget of the Transaction and set its priority fee.
+    ///
+    /// The Transaction is simulated to measure the compute units it consumes,
+    /// the limit is raised by [`PriorityFeeConfig::margin`], and the price is
+    /// taken from `getRecentPrioritizationFees` for the writable accounts at
+    /// [`PriorityFeeConfig::percentile`]. Any existing compute budget
+    /// instructions are replaced by `setComputeUnitLimit` and
+    /// `setComputeUnitPrice`, prepended in that order.
+    ///
+    /// `feePayer` and `recentBlockhash` must be set; the Transaction must be
+    /// signed afterwards since its message changes.
+    pub async fn set_priority_fee(
+        &self,
+        connection: &Connection,
+        config: &PriorityFeeConfig,
+    ) -> Result<PriorityFee> {
+        let mut instructions = vec![];
+        for instruction in self.instructions().iter() {
+            let instruction = TransactionInstruction::from(instruction);
+            if Instruction::try_from(&instruction)?.program_id != solana_sdk::compute_budget::id() {
+                instructions.push(instruction);
+            }
+        }
+
+        // `nonceAdvance` lives in `nonceInfo` and is prepended when compiling
+        let simulated = Transaction::from_bytes(&self.to_bytes()?)?;
+        simulated.set_nonce_info(self.nonce_info());
+        simulated.set_instructions(instructions_to_array(
+            &[ComputeBudgetProgram::set_compute_unit_limit(
+                MAX_COMPUTE_UNIT_LIMIT,
+            )?],
+            &instructions,
+        ));
+        let simulation_config = SimulateTransactionConfig::new()
+            .sig_verify(false)
+            .replace_recent_blockhash(true);
+        let simulation = connection
+            .simulate_transaction(&simulated, Some(simulation_config))
+            .await?;
+        if let Some(err) = simulation.err {
+            let info = JsErrorInfo {
+                message: format!("Transaction simulation failed: {err}"),
+                logs: simulation.logs,
+                ..Default::default()
+            };
+            return Err(match err {
+                TransactionStatusError::Known(err) => Error::Transaction {
+                    err,
+                    info: Box::new(info),
+                },
+                TransactionStatusError::Unknown(_) => Error::JsValue(Box::new(info)),
+            });
+        }
+        let units_consumed = simulation
+            .units_consumed
+            .ok_or_else(|| Error::InvalidField("unitsConsumed".to_string()))?;
+        let compute_unit_limit = ((units_consumed as f64) * (1.0 + config.margin.max(0.0)))
+            .ceil()
+            .min(MAX_COMPUTE_UNIT_LIMIT as f64) as u32;
+
+        let mut writable = vec![];
+        for instruction in &instructions {
+            for account in Instruction::try_from(instruction)?.accounts {
+                if account.is_writable && !writable.contains(&account.pubkey) {
+                    writable.push(account.pubkey);
+                }
+            }
+        }
+        writable.truncate(MAX_PRIORITIZATION_FEE_ACCOUNTS);
+        let fees = connection.get_recent_prioritization_fees(&writable).await?;
+        let mut micro_lamports = fee_percentile(
+            fees.into_iter().map(|(_, fee)| fee).collect(),
+            config.percentile,
+        );
+        if let Some(max_micro_lamports) = config.max_micro_lamports {
+            micro_lamports = micro_lamports.min(max_micro_lamports);
+        }
+
+        self.set_instructions(instructions_to_array(
+            &[
+                ComputeBudgetProgram::set_compute_unit_limit(compute_unit_limit)?,
+                ComputeBudgetProgram::set_compute_unit_price(micro_lamports)?,
+            ],
+            &instructions,
+        ));
+
+        Ok(PriorityFee {
+            units_consumed,
+            compute_unit_limit,
+            micro_lamports,
+        })
+    }
+
+    /// Serialize the Transaction in the wire format without requiring
+    /// or verifying signatures, keeping any partial signatures in place.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let config = SerializeConfig::new()
+            .require_all_signatures(false)
+            .verify_signatures(false);
+        let bytes = self.try_serialize(config)?;
+        Ok(Uint8Array::new(&bytes).to_vec())
+    }
+}
+
+fn signers_to_jsvalues(signers: &[&Keypair]) -> Box<[JsValue]> {
+    signers
+        .iter()
+        .map(|signer| JsValue::from(*signer))
+        .collect()
+}
+
+impl TryFrom<&SolanaTransaction> for Transaction {
+    type Error = crate::error::Error;
+
+    fn try_from(tx: &SolanaTransaction) -> Result<Self> {
+        let bytes = bincode::serialize(tx)?;
+        Transaction::from_bytes(&bytes)
+    }
+}
+
+impl TryFrom<&Transaction> for SolanaTransaction {
+    type Error = crate::error::Error;
+
+    fn try_from(tx: &Transaction) -> Result<Self> {
+        Ok(bincode::deserialize(&tx.to_bytes()?)?)
+    }
+}
 
 impl OptionsTrait for SerializeConfig {}
+
+impl SerializeConfig {
+    /// Require all transaction signatures be present (default: true)
+    pub fn require_all_signatures(self, require_all_signatures: bool) -> Self {
+        self.set(
+            "requireAllSignatures",
+            JsValue::from(require_all_signatures),
+        )
+    }
+
+    /// Verify provided signatures (default: true)
+    pub fn verify_signatures(self, verify_signatures: bool) -> Self {
+        self.set("verifySignatures", JsValue::from(verify_signatures))
+    }
+}
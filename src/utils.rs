@@ -2,14 +2,235 @@
 //! Utility functions.
 //!
 
+use crate::error::Error;
 use crate::imports::*;
+use solana_sdk::signature::Signature;
+use solana_sdk::transaction::TransactionError;
+
+/// Resolve a class exported by the web3.js module passed to [`init_solana_web3_sys`]
+pub fn web3_class(name: &str) -> Result<js_sys::Function> {
+    let class = js_sys::Reflect::get(&solana()?, &JsValue::from(name))?;
+    if !class.is_function() {
+        return Err(JsValue::from(format!("web3.js does not export `{name}`")).into());
+    }
+    Ok(class.into())
+}
+
+/// Construct an instance of a web3.js class
+pub fn web3_construct<T: JsCast>(class: &str, args: &[&JsValue]) -> Result<T> {
+    let args = args.iter().collect::<Array>();
+    let instance = js_sys::Reflect::construct(&web3_class(class)?, &args)?;
+    Ok(instance.unchecked_into())
+}
+
+/// Call a static method of a web3.js class
+pub fn web3_call_static<T: JsCast>(class: &str, method: &str, args: &[&JsValue]) -> Result<T> {
+    let class = web3_class(class)?;
+    let function = js_sys::Reflect::get(&class, &JsValue::from(method))?;
+    let args = args.iter().collect::<Array>();
+    let result = js_sys::Reflect::apply(&function.into(), &class, &args)?;
+    Ok(result.unchecked_into())
+}
 
 pub fn pubkey_to_jsvalue(pubkey: &Pubkey) -> Result<JsValue> {
-    let pubkey_bytes = pubkey.to_bytes();
-    let u8arr = Uint8Array::from(&pubkey_bytes[..]);
-    let pkargs = Array::new_with_length(1);
-    pkargs.set(0u32, u8arr.into());
-    let ctor = js_sys::Reflect::get(&solana()?, &JsValue::from("PublicKey"))?;
-    let pk_jsv = js_sys::Reflect::construct(&ctor.into(), &pkargs)?;
-    Ok(pk_jsv)
+    let pubkey_bytes = Uint8Array::from(&pubkey.to_bytes()[..]);
+    web3_construct("PublicKey", &[&pubkey_bytes])
+}
+
+/// Error of a failed transaction as reported by the cluster. Errors that the
+/// pinned `solana-sdk` cannot decode, such as variants added by newer
+/// validators, are kept as their raw JSON instead of failing the caller.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransactionStatusError {
+    Known(TransactionError),
+    Unknown(String),
+}
+
+impl TransactionStatusError {
+    /// Decode the JSON of a `TransactionError`
+    pub fn from_json(json: &str) -> Self {
+        match serde_json::from_str(json) {
+            Ok(err) => Self::Known(err),
+            Err(_) => Self::Unknown(json.to_string()),
+        }
+    }
+
+    /// The decoded [`TransactionError`], `None` for unknown errors
+    pub fn transaction_error(&self) -> Option<&TransactionError> {
+        match self {
+            Self::Known(err) => Some(err),
+            Self::Unknown(_) => None,
+        }
+    }
+
+    /// Convert into the decoded [`TransactionError`], `None` for unknown errors
+    pub fn into_transaction_error(self) -> Option<TransactionError> {
+        match self {
+            Self::Known(err) => Some(err),
+            Self::Unknown(_) => None,
+        }
+    }
+}
+
+impl From<TransactionError> for TransactionStatusError {
+    fn from(err: TransactionError) -> Self {
+        Self::Known(err)
+    }
+}
+
+impl std::fmt::Display for TransactionStatusError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Known(err) => write!(f, "{err}"),
+            Self::Unknown(json) => write!(f, "{json}"),
+        }
+    }
+}
+
+/// Decode a JSON-RPC `TransactionError` object or unit variant name (`null`
+/// when the transaction succeeded)
+pub fn transaction_error_from_jsvalue(err: &JsValue) -> Result<Option<TransactionStatusError>> {
+    if err.is_null() || err.is_undefined() {
+        return Ok(None);
+    }
+    let json = match err.as_string() {
+        Some(name) => serde_json::to_string(&name)?,
+        None => String::from(js_sys::JSON::stringify(err)?),
+    };
+    Ok(Some(TransactionStatusError::from_json(&json)))
+}
+
+/// Decode a transaction signature returned by a wallet or RPC call, given either
+/// as a base58 string, raw bytes or an object with a `signature` property
+pub fn signature_from_jsvalue(value: &JsValue) -> Result<Signature> {
+    if let Some(signature) = value.as_string() {
+        return Ok(signature.parse()?);
+    }
+    if value.is_instance_of::<Uint8Array>() {
+        return Signature::try_from(Uint8Array::new(value).to_vec())
+            .map_err(|_| JsValue::from("Invalid signature length").into());
+    }
+    if value.is_object() {
+        let signature = js_sys::Reflect::get(value, &JsValue::from("signature"))?;
+        if !signature.is_undefined() {
+            return signature_from_jsvalue(&signature);
+        }
+    }
+    Err(JsValue::from(format!("Unable to decode signature: {value:?}")).into())
+}
+
+/// Largest integer a JavaScript `number` represents exactly (`Number.MAX_SAFE_INTEGER`)
+pub const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_991.0;
+
+/// Convert a JavaScript `number` or `bigint` into an exact [`u64`]; `field`
+/// names the value in the returned error
+pub fn u64_from_jsvalue(value: &JsValue, field: &str) -> Result<u64> {
+    if value.is_bigint() {
+        let value = js_sys::BigInt::from(value.clone())
+            .to_string(10)
+            .map_err(|_| Error::InvalidField(field.to_string()))?;
+        return u64_from_decimal(&String::from(value), field);
+    }
+    match value.as_f64() {
+        Some(number) => u64_from_f64(number, field),
+        None => Err(Error::InvalidField(field.to_string())),
+    }
+}
+
+/// Convert the decimal string form of a `bigint` into a [`u64`]
+pub(crate) fn u64_from_decimal(value: &str, field: &str) -> Result<u64> {
+    if value.is_empty() || !value.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err(Error::InvalidField(field.to_string()));
+    }
+    value
+        .parse::<u64>()
+        .map_err(|_| Error::Overflow(field.to_string()))
+}
+
+/// Convert a JavaScript `number` into a [`u64`], rejecting values above
+/// [`MAX_SAFE_INTEGER`] that may have lost precision
+pub(crate) fn u64_from_f64(number: f64, field: &str) -> Result<u64> {
+    if !number.is_finite() || number < 0.0 || number.fract() != 0.0 {
+        return Err(Error::InvalidField(field.to_string()));
+    }
+    if number > MAX_SAFE_INTEGER {
+        return Err(Error::Overflow(field.to_string()));
+    }
+    Ok(number as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transaction_status_error_decodes_known_errors() {
+        assert_eq!(
+            TransactionStatusError::from_json(r#""BlockhashNotFound""#),
+            TransactionStatusError::Known(TransactionError::BlockhashNotFound)
+        );
+        assert_eq!(
+            TransactionStatusError::from_json(r#"{"InstructionError":[1,{"Custom":6001}]}"#)
+                .into_transaction_error(),
+            Some(TransactionError::InstructionError(
+                1,
+                solana_program::instruction::InstructionError::Custom(6001)
+            ))
+        );
+    }
+
+    #[test]
+    fn transaction_status_error_keeps_unknown_errors() {
+        for json in [
+            r#""ProgramCacheHitMaxLimit""#,
+            r#"{"InstructionError":[0,"SomeFutureInstructionError"]}"#,
+        ] {
+            let err = TransactionStatusError::from_json(json);
+            assert_eq!(err, TransactionStatusError::Unknown(json.to_string()));
+            assert_eq!(err.transaction_error(), None);
+            assert_eq!(err.to_string(), json);
+        }
+    }
+
+    #[test]
+    fn u64_from_f64_accepts_safe_integers() {
+        assert_eq!(u64_from_f64(0.0, "value").unwrap(), 0);
+        assert_eq!(u64_from_f64(5000.0, "value").unwrap(), 5000);
+        assert_eq!(
+            u64_from_f64(MAX_SAFE_INTEGER, "value").unwrap(),
+            9_007_199_254_740_991
+        );
+    }
+
+    #[test]
+    fn u64_from_f64_rejects_inexact_numbers() {
+        assert!(matches!(
+            u64_from_f64(MAX_SAFE_INTEGER + 1.0, "value"),
+            Err(Error::Overflow(_))
+        ));
+        for number in [-1.0, 0.5, f64::NAN, f64::INFINITY] {
+            assert!(matches!(
+                u64_from_f64(number, "value"),
+                Err(Error::InvalidField(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn u64_from_decimal_covers_the_full_range() {
+        assert_eq!(
+            u64_from_decimal("18446744073709551615", "value").unwrap(),
+            u64::MAX
+        );
+        assert!(matches!(
+            u64_from_decimal("18446744073709551616", "value"),
+            Err(Error::Overflow(_))
+        ));
+        for value in ["", "-1", "1.5", "0x10"] {
+            assert!(matches!(
+                u64_from_decimal(value, "value"),
+                Err(Error::InvalidField(_))
+            ));
+        }
+    }
 }
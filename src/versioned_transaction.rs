@@ -0,0 +1,86 @@
+//!
+//! [`VersionedTransaction`](https://solana-labs.github.io/solana-web3.js/classes/VersionedTransaction.html) class bindings.
+//!
+
+use crate::imports::*;
+use crate::message::{AddressLookupTableAccount, MessageV0, TransactionMessage};
+use solana_program::hash::Hash;
+use solana_program::instruction::Instruction;
+use solana_sdk::transaction::VersionedTransaction as SolanaVersionedTransaction;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(extends = Object)]
+    #[derive(Debug, Clone)]
+    /// VersionedTransaction
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/VersionedTransaction.html)
+    ///
+    pub type VersionedTransaction;
+
+    #[wasm_bindgen(method, js_name = "serialize")]
+    /// Serialize the VersionedTransaction in the wire format
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/VersionedTransaction.html#serialize)
+    ///
+    pub fn serialize(this: &VersionedTransaction) -> Vec<u8>;
+
+    #[wasm_bindgen(getter, method)]
+    /// Getter: Transaction version (`"legacy"` or `0`)
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/VersionedTransaction.html#version)
+    ///
+    pub fn version(this: &VersionedTransaction) -> JsValue;
+}
+
+impl VersionedTransaction {
+    /// Construct an unsigned VersionedTransaction from a [`MessageV0`]
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/VersionedTransaction.html#constructor)
+    ///
+    pub fn new(message: &MessageV0) -> Result<VersionedTransaction> {
+        web3_construct("VersionedTransaction", &[message])
+    }
+
+    /// Deserialize VersionedTransaction from the wire format
+    ///
+    /// ⧉ [Solana Documentation](https://solana-labs.github.io/solana-web3.js/classes/VersionedTransaction.html#deserialize)
+    ///
+    pub fn deserialize(serialized_transaction: &[u8]) -> Result<VersionedTransaction> {
+        web3_call_static(
+            "VersionedTransaction",
+            "deserialize",
+            &[&Uint8Array::from(serialized_transaction)],
+        )
+    }
+
+    /// Compile native [`Instruction`]s into an unsigned v0 [`VersionedTransaction`]
+    /// resolving accounts through the supplied lookup tables
+    pub fn try_new_v0(
+        payer_key: &Pubkey,
+        instructions: &[Instruction],
+        recent_blockhash: &Hash,
+        address_lookup_table_accounts: &[AddressLookupTableAccount],
+    ) -> Result<Self> {
+        let message = TransactionMessage::try_new(payer_key, instructions, recent_blockhash)?
+            .compile_to_v0_message(address_lookup_table_accounts)?;
+        VersionedTransaction::new(&message)
+    }
+}
+
+impl TryFrom<&SolanaVersionedTransaction> for VersionedTransaction {
+    type Error = crate::error::Error;
+
+    fn try_from(tx: &SolanaVersionedTransaction) -> Result<Self> {
+        let bytes = bincode::serialize(tx)?;
+        VersionedTransaction::deserialize(&bytes)
+    }
+}
+
+impl TryFrom<&VersionedTransaction> for SolanaVersionedTransaction {
+    type Error = crate::error::Error;
+
+    fn try_from(tx: &VersionedTransaction) -> Result<Self> {
+        Ok(bincode::deserialize(&tx.serialize())?)
+    }
+}
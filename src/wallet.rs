@@ -2,40 +2,84 @@
 //! `WalletAdapter` class bindings.
 //!
 
+use crate::error::{is_wallet_rejection, Error};
 use crate::imports::*;
 use crate::prelude::Connection;
+use crate::publickey::PublicKey;
 use crate::transaction::*;
+use async_trait::async_trait;
+use solana_sdk::signature::Signature;
+
+/// Common interface implemented by the wallets this crate can talk to,
+/// either a [`WalletAdapter`] instance or a Wallet Standard
+/// [`StandardWalletAdapter`](crate::wallet_standard::StandardWalletAdapter).
+#[async_trait(?Send)]
+pub trait WalletAdapterTrait {
+    /// Public key of the connected account, `None` if the wallet is not connected
+    fn pubkey(&self) -> Option<Pubkey>;
+
+    /// Request the wallet to connect
+    async fn connect(&self) -> Result<()>;
+
+    /// Request the wallet to sign the transaction, returning the signed transaction
+    async fn sign_transaction(&self, tx: &Transaction) -> Result<Transaction>;
+
+    /// Request the wallet to sign and submit the transaction
+    async fn sign_and_send_transaction(&self, tx: &Transaction) -> Result<Signature>;
+
+    /// Request the wallet to sign all transactions with a single approval
+    async fn sign_all_transactions(&self, txs: &[Transaction]) -> Result<Vec<Transaction>>;
+
+    /// Request the wallet to sign an arbitrary message; the signature can be
+    /// checked with [`verify_message_signature`] against [`pubkey()`](Self::pubkey)
+    async fn sign_message(&self, message: &[u8]) -> Result<Signature>;
+}
 
 #[wasm_bindgen]
 extern "C" {
-    #[wasm_bindgen(js_namespace=solanaWeb3, js_name = PhantomWalletAdapter)]
+    #[wasm_bindgen(extends = Object)]
     #[derive(Debug, Clone)]
     /// WalletAdapter
     ///
     pub type WalletAdapter;
 
-    #[wasm_bindgen(getter, method, js_namespace=solanaWeb3, js_name = "publicKey")]
+    #[wasm_bindgen(getter, method, js_name = "publicKey")]
     /// get pubKey
     ///
-    pub fn pubkey(this: &WalletAdapter) -> JsValue;
+    pub fn pubkey_impl(this: &WalletAdapter) -> JsValue;
 
-    #[wasm_bindgen(method, catch, js_namespace=solanaWeb3, js_name = "signTransaction")]
+    #[wasm_bindgen(method, catch, js_name = "connect")]
+    /// connect
+    ///
+    pub async fn connect_impl(this: &WalletAdapter) -> Result<JsValue>;
+
+    #[wasm_bindgen(method, catch, js_name = "signTransaction")]
     /// sign transaction
     ///
     pub async fn sign_transaction_impl(this: &WalletAdapter, tx: Transaction) -> Result<JsValue>;
 
-    #[wasm_bindgen(method, catch, js_namespace=solanaWeb3, js_name = "signAndSendTransaction")]
+    #[wasm_bindgen(method, catch, js_name = "signAndSendTransaction")]
     /// sign and send transaction
     ///
-    pub async fn sign_and_send_transaction(
+    pub async fn sign_and_send_transaction_impl(
         this: &WalletAdapter,
         tx: Transaction,
     ) -> Result<JsValue>;
 
-    #[wasm_bindgen(method, catch, js_namespace=solanaWeb3, js_name = "sendTransaction")]
+    #[wasm_bindgen(method, catch, js_name = "signAllTransactions")]
+    /// sign all transactions
+    ///
+    pub async fn sign_all_transactions_impl(this: &WalletAdapter, txs: Array) -> Result<JsValue>;
+
+    #[wasm_bindgen(method, catch, js_name = "signMessage")]
+    /// sign message
+    ///
+    pub async fn sign_message_impl(this: &WalletAdapter, message: Uint8Array) -> Result<JsValue>;
+
+    #[wasm_bindgen(method, catch, js_name = "sendTransaction")]
     /// send transaction
     ///
-    pub async fn send_transaction(
+    pub async fn send_transaction_impl(
         this: &WalletAdapter,
         tx: Transaction,
         con: Connection,
@@ -43,7 +87,147 @@ extern "C" {
 }
 
 impl WalletAdapter {
-    pub async fn sign_transaction(&self, tx: &Transaction) -> Result<JsValue> {
-        self.sign_transaction_impl(tx.clone()).await
+    /// Public key of the connected account, `None` if the wallet is not connected
+    pub fn pubkey(&self) -> Option<Pubkey> {
+        let pubkey = self.pubkey_impl();
+        if !pubkey.is_object() {
+            return None;
+        }
+        PublicKey::from(pubkey).try_into().ok()
+    }
+
+    pub async fn sign_transaction(&self, tx: &Transaction) -> Result<Transaction> {
+        let signed = self
+            .sign_transaction_impl(tx.clone())
+            .await
+            .map_err(wallet_error)?;
+        Ok(signed.into())
+    }
+
+    pub async fn sign_and_send_transaction(&self, tx: &Transaction) -> Result<Signature> {
+        let result = self
+            .sign_and_send_transaction_impl(tx.clone())
+            .await
+            .map_err(wallet_error)?;
+        signature_from_jsvalue(&result)
+    }
+
+    pub async fn sign_all_transactions(&self, txs: &[Transaction]) -> Result<Vec<Transaction>> {
+        let list = Array::new();
+        for tx in txs {
+            list.push(tx.as_ref());
+        }
+        let signed = self
+            .sign_all_transactions_impl(list)
+            .await
+            .map_err(wallet_error)?;
+        Ok(Array::from(&signed).iter().map(Transaction::from).collect())
+    }
+
+    pub async fn sign_message(&self, message: &[u8]) -> Result<Signature> {
+        let result = self
+            .sign_message_impl(Uint8Array::from(message))
+            .await
+            .map_err(wallet_error)?;
+        signature_from_jsvalue(&result)
+    }
+
+    pub async fn send_transaction(&self, tx: &Transaction, con: &Connection) -> Result<Signature> {
+        let result = self
+            .send_transaction_impl(tx.clone(), con.clone())
+            .await
+            .map_err(wallet_error)?;
+        signature_from_jsvalue(&result)
+    }
+}
+
+#[async_trait(?Send)]
+impl WalletAdapterTrait for WalletAdapter {
+    fn pubkey(&self) -> Option<Pubkey> {
+        WalletAdapter::pubkey(self)
+    }
+
+    async fn connect(&self) -> Result<()> {
+        self.connect_impl().await.map_err(wallet_error)?;
+        Ok(())
+    }
+
+    async fn sign_transaction(&self, tx: &Transaction) -> Result<Transaction> {
+        WalletAdapter::sign_transaction(self, tx).await
+    }
+
+    async fn sign_and_send_transaction(&self, tx: &Transaction) -> Result<Signature> {
+        WalletAdapter::sign_and_send_transaction(self, tx).await
+    }
+
+    async fn sign_all_transactions(&self, txs: &[Transaction]) -> Result<Vec<Transaction>> {
+        WalletAdapter::sign_all_transactions(self, txs).await
+    }
+
+    async fn sign_message(&self, message: &[u8]) -> Result<Signature> {
+        WalletAdapter::sign_message(self, message).await
+    }
+}
+
+/// Verify an ed25519 `signature` of `message` produced by `pubkey`
+pub fn verify_message_signature(pubkey: &Pubkey, message: &[u8], signature: &Signature) -> bool {
+    let Ok(pubkey) = ed25519_dalek::PublicKey::from_bytes(pubkey.as_ref()) else {
+        return false;
+    };
+    let Ok(signature) = ed25519_dalek::Signature::from_bytes(signature.as_ref()) else {
+        return false;
+    };
+    pubkey.verify_strict(message, &signature).is_ok()
+}
+
+/// Convert errors thrown by a wallet into [`Error::WalletRejected`] when
+/// the user declined the request, as decided by [`is_wallet_rejection`].
+/// Other signing, RPC and preflight failures are returned unchanged.
+pub fn wallet_error(err: Error) -> Error {
+    match err.js_error_info() {
+        Some(info) if !matches!(err, Error::WalletRejected { .. }) && is_wallet_rejection(info) => {
+            Error::WalletRejected {
+                message: info.message.clone(),
+                info: Box::new(info.clone()),
+            }
+        }
+        _ => err,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::JsErrorInfo;
+
+    fn js_error(name: &str, code: Option<&str>) -> Error {
+        Error::JsValue(Box::new(JsErrorInfo {
+            name: name.to_string(),
+            message: "Unexpected error".to_string(),
+            code: code.map(String::from),
+            ..Default::default()
+        }))
+    }
+
+    #[test]
+    fn wrapped_rejection() {
+        let err = wallet_error(js_error("WalletSignTransactionError", Some("4001")));
+        assert!(matches!(err, Error::WalletRejected { .. }));
+        let err = wallet_error(js_error("WalletWindowClosedError", None));
+        assert!(matches!(err, Error::WalletRejected { .. }));
+    }
+
+    #[test]
+    fn wrapped_failure_is_not_rejection() {
+        for name in [
+            "WalletSignTransactionError",
+            "WalletSignMessageError",
+            "WalletSignInError",
+        ] {
+            let err = wallet_error(js_error(name, Some("-32603")));
+            assert!(matches!(err, Error::JsValue(ref info) if info.name == name));
+            let err = wallet_error(js_error(name, None));
+            assert!(matches!(err, Error::JsValue(_)));
+        }
     }
 }
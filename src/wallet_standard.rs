@@ -0,0 +1,446 @@
+//!
+//! [Wallet Standard](https://github.com/wallet-standard/wallet-standard) registry and wallet bindings.
+//!
+
+use crate::imports::*;
+use crate::transaction::Transaction;
+use crate::wallet::{wallet_error, WalletAdapterTrait};
+use async_trait::async_trait;
+use js_sys::{Function, Promise, Reflect};
+use solana_sdk::signature::Signature;
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen_futures::JsFuture;
+
+pub const SOLANA_MAINNET_CHAIN: &str = "solana:mainnet";
+pub const SOLANA_DEVNET_CHAIN: &str = "solana:devnet";
+pub const SOLANA_TESTNET_CHAIN: &str = "solana:testnet";
+
+/// Wallet Standard features used by this crate
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WalletFeature {
+    Connect,
+    Disconnect,
+    SignTransaction,
+    SignAndSendTransaction,
+    SignMessage,
+    SignIn,
+}
+
+impl WalletFeature {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WalletFeature::Connect => "standard:connect",
+            WalletFeature::Disconnect => "standard:disconnect",
+            WalletFeature::SignTransaction => "solana:signTransaction",
+            WalletFeature::SignAndSendTransaction => "solana:signAndSendTransaction",
+            WalletFeature::SignMessage => "solana:signMessage",
+            WalletFeature::SignIn => "solana:signIn",
+        }
+    }
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(extends = Object)]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    /// Wallet registered through the Wallet Standard
+    ///
+    /// ⧉ [Wallet Standard](https://github.com/wallet-standard/wallet-standard/blob/master/packages/core/base/src/wallet.ts)
+    ///
+    pub type StandardWallet;
+
+    #[wasm_bindgen(getter, method)]
+    /// Getter: Name of the wallet
+    pub fn name(this: &StandardWallet) -> String;
+
+    #[wasm_bindgen(getter, method)]
+    /// Getter: Data URI of the wallet icon
+    pub fn icon(this: &StandardWallet) -> String;
+
+    #[wasm_bindgen(getter, method)]
+    /// Getter: Wallet Standard version implemented by the wallet
+    pub fn version(this: &StandardWallet) -> String;
+
+    #[wasm_bindgen(getter, method)]
+    /// Getter: Chains supported by the wallet (e.g. `solana:mainnet`)
+    pub fn chains(this: &StandardWallet) -> Array;
+
+    #[wasm_bindgen(getter, method)]
+    /// Getter: Features supported by the wallet, keyed by feature name
+    pub fn features(this: &StandardWallet) -> Object;
+
+    #[wasm_bindgen(getter, method)]
+    /// Getter: Accounts the application has been authorized to use
+    pub fn accounts(this: &StandardWallet) -> Array;
+
+    #[wasm_bindgen(extends = Object)]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    /// Account exposed by a [`StandardWallet`]
+    ///
+    /// ⧉ [Wallet Standard](https://github.com/wallet-standard/wallet-standard/blob/master/packages/core/base/src/wallet.ts)
+    ///
+    pub type WalletAccount;
+
+    #[wasm_bindgen(getter, method)]
+    /// Getter: Address of the account (base58 encoded public key)
+    pub fn address(this: &WalletAccount) -> String;
+
+    #[wasm_bindgen(getter, method, js_name = "publicKey")]
+    /// Getter: Public key bytes of the account
+    pub fn public_key(this: &WalletAccount) -> Vec<u8>;
+
+    #[wasm_bindgen(getter, method)]
+    /// Getter: Chains supported by the account
+    pub fn chains(this: &WalletAccount) -> Array;
+
+    #[wasm_bindgen(getter, method)]
+    /// Getter: Features supported by the account
+    pub fn features(this: &WalletAccount) -> Array;
+
+    #[wasm_bindgen(getter, method)]
+    /// Getter: Optional user-friendly account label
+    pub fn label(this: &WalletAccount) -> Option<String>;
+
+    #[wasm_bindgen(extends = Object, js_name = CustomEvent)]
+    #[derive(Debug, Clone)]
+    type WalletStandardEvent;
+
+    #[wasm_bindgen(catch, constructor, js_class = "CustomEvent")]
+    fn new(event_type: &str, init: &Object) -> Result<WalletStandardEvent>;
+
+    #[wasm_bindgen(catch, js_name = addEventListener)]
+    fn add_event_listener(event_type: &str, listener: &Function) -> Result<()>;
+
+    #[wasm_bindgen(catch, js_name = removeEventListener)]
+    fn remove_event_listener(event_type: &str, listener: &Function) -> Result<()>;
+
+    #[wasm_bindgen(catch, js_name = dispatchEvent)]
+    fn dispatch_event(event: &WalletStandardEvent) -> Result<bool>;
+}
+
+impl StandardWallet {
+    /// Feature object registered under the given name
+    pub fn feature(&self, feature: WalletFeature) -> Option<Object> {
+        Reflect::get(&self.features(), &JsValue::from(feature.as_str()))
+            .ok()
+            .filter(|feature| feature.is_object())
+            .map(Object::from)
+    }
+
+    /// `true` if the wallet implements the given feature
+    pub fn supports(&self, feature: WalletFeature) -> bool {
+        self.feature(feature).is_some()
+    }
+
+    /// `true` if the wallet supports a Solana chain and can sign transactions
+    pub fn is_solana(&self) -> bool {
+        let has_chain = self.chains().iter().any(|chain| {
+            chain
+                .as_string()
+                .map(|chain| chain.starts_with("solana:"))
+                .unwrap_or(false)
+        });
+        has_chain
+            && (self.supports(WalletFeature::SignTransaction)
+                || self.supports(WalletFeature::SignAndSendTransaction))
+    }
+
+    /// Accounts the application has been authorized to use
+    pub fn account_list(&self) -> Vec<WalletAccount> {
+        self.accounts().iter().map(WalletAccount::from).collect()
+    }
+
+    /// Invoke `method` of the given feature with `args` and await the returned promise
+    pub async fn call_feature(
+        &self,
+        feature: WalletFeature,
+        method: &str,
+        args: &Array,
+    ) -> Result<JsValue> {
+        let target = self.feature(feature).ok_or_else(|| {
+            JsValue::from(format!(
+                "Wallet `{}` does not support `{}`",
+                self.name(),
+                feature.as_str()
+            ))
+        })?;
+        let function = Function::from(Reflect::get(&target, &JsValue::from(method))?);
+        let promise = Promise::from(Reflect::apply(&function, &target, args)?);
+        JsFuture::from(promise)
+            .await
+            .map_err(|err| wallet_error(err.into()))
+    }
+}
+
+/// Registry of wallets announced through the Wallet Standard
+/// `wallet-standard:register-wallet` / `wallet-standard:app-ready` events.
+///
+/// Dropping the registry stops listening for `wallet-standard:register-wallet`.
+/// Wallets may keep the `register` callback they were handed; it is owned by
+/// JavaScript and stays callable, but registrations made through it after the
+/// drop are no longer observable.
+pub struct WalletRegistry {
+    wallets: Rc<RefCell<Vec<StandardWallet>>>,
+    listener: Closure<dyn FnMut(JsValue)>,
+}
+
+impl WalletRegistry {
+    /// Start listening for wallet registrations and announce the application to
+    /// wallets that have already loaded
+    pub fn try_new() -> Result<Self> {
+        let wallets = Rc::new(RefCell::new(Vec::<StandardWallet>::new()));
+
+        let register = {
+            let wallets = wallets.clone();
+            Closure::<dyn FnMut(JsValue) -> JsValue>::new(move |wallet: JsValue| {
+                let wallet = wallet.is_object().then(|| StandardWallet::from(wallet));
+                if let Some(wallet) = &wallet {
+                    let mut wallets = wallets.borrow_mut();
+                    if !wallets.contains(wallet) {
+                        wallets.push(wallet.clone());
+                    }
+                }
+                // `register()` returns the function that unregisters the wallet
+                let wallets = wallets.clone();
+                Closure::<dyn FnMut()>::new(move || {
+                    if let Some(wallet) = &wallet {
+                        wallets
+                            .borrow_mut()
+                            .retain(|registered| registered != wallet);
+                    }
+                })
+                .into_js_value()
+            })
+            .into_js_value()
+        };
+
+        let api = Object::new();
+        Reflect::set(&api, &JsValue::from("register"), &register)?;
+
+        let listener = {
+            let api = api.clone();
+            Closure::new(move |event: JsValue| {
+                if let Ok(callback) = Reflect::get(&event, &JsValue::from("detail")) {
+                    if let Some(callback) = callback.dyn_ref::<Function>() {
+                        let _ = callback.call1(&JsValue::UNDEFINED, &api);
+                    }
+                }
+            })
+        };
+        add_event_listener(
+            "wallet-standard:register-wallet",
+            listener.as_ref().unchecked_ref(),
+        )?;
+        // built before announcing so that `Drop` removes the listener on error
+        let registry = Self { wallets, listener };
+
+        let init = Object::new();
+        Reflect::set(&init, &JsValue::from("detail"), &api)?;
+        dispatch_event(&WalletStandardEvent::new(
+            "wallet-standard:app-ready",
+            &init,
+        )?)?;
+
+        Ok(registry)
+    }
+
+    /// All registered wallets
+    pub fn wallets(&self) -> Vec<StandardWallet> {
+        self.wallets.borrow().clone()
+    }
+
+    /// Registered wallets that support Solana
+    pub fn solana_wallets(&self) -> Vec<StandardWallet> {
+        self.wallets
+            .borrow()
+            .iter()
+            .filter(|wallet| wallet.is_solana())
+            .cloned()
+            .collect()
+    }
+
+    /// Registered wallet with the given name
+    pub fn get(&self, name: &str) -> Option<StandardWallet> {
+        self.wallets
+            .borrow()
+            .iter()
+            .find(|wallet| wallet.name() == name)
+            .cloned()
+    }
+}
+
+impl Drop for WalletRegistry {
+    fn drop(&mut self) {
+        let _ = remove_event_listener(
+            "wallet-standard:register-wallet",
+            self.listener.as_ref().unchecked_ref(),
+        );
+    }
+}
+
+/// [`WalletAdapterTrait`] implementation on top of a [`StandardWallet`]
+pub struct StandardWalletAdapter {
+    wallet: StandardWallet,
+    chain: String,
+    account: RefCell<Option<WalletAccount>>,
+}
+
+impl StandardWalletAdapter {
+    pub fn new(wallet: StandardWallet) -> Self {
+        Self {
+            wallet,
+            chain: SOLANA_MAINNET_CHAIN.to_string(),
+            account: RefCell::new(None),
+        }
+    }
+
+    /// Set the chain passed to the wallet (default: `solana:mainnet`)
+    pub fn with_chain(mut self, chain: &str) -> Self {
+        self.chain = chain.to_string();
+        self
+    }
+
+    pub fn wallet(&self) -> &StandardWallet {
+        &self.wallet
+    }
+
+    pub fn chain(&self) -> &str {
+        &self.chain
+    }
+
+    /// Account selected when the wallet was connected
+    pub fn account(&self) -> Option<WalletAccount> {
+        self.account.borrow().clone()
+    }
+
+    pub(crate) fn set_account(&self, account: WalletAccount) {
+        self.account.replace(Some(account));
+    }
+
+    fn connected_account(&self) -> Result<WalletAccount> {
+        self.account()
+            .ok_or_else(|| JsValue::from("Wallet is not connected").into())
+    }
+
+    fn transaction_input(&self, tx: &Transaction) -> Result<Object> {
+        let input = Object::new();
+        Reflect::set(
+            &input,
+            &JsValue::from("account"),
+            &self.connected_account()?.into(),
+        )?;
+        Reflect::set(
+            &input,
+            &JsValue::from("transaction"),
+            &Uint8Array::from(tx.to_bytes()?.as_slice()),
+        )?;
+        Reflect::set(&input, &JsValue::from("chain"), &JsValue::from(&self.chain))?;
+        Ok(input)
+    }
+
+    async fn outputs(&self, feature: WalletFeature, method: &str, inputs: &Array) -> Result<Array> {
+        let outputs = self.wallet.call_feature(feature, method, inputs).await?;
+        Ok(Array::from(&outputs))
+    }
+
+    async fn first_output(
+        &self,
+        feature: WalletFeature,
+        method: &str,
+        input: Object,
+        field: &str,
+    ) -> Result<JsValue> {
+        let output = self
+            .outputs(feature, method, &Array::of1(&input))
+            .await?
+            .get(0);
+        Ok(Reflect::get(&output, &JsValue::from(field))?)
+    }
+}
+
+#[async_trait(?Send)]
+impl WalletAdapterTrait for StandardWalletAdapter {
+    fn pubkey(&self) -> Option<Pubkey> {
+        Pubkey::try_from(self.account()?.public_key()).ok()
+    }
+
+    async fn connect(&self) -> Result<()> {
+        let output = self
+            .wallet
+            .call_feature(WalletFeature::Connect, "connect", &Array::new())
+            .await?;
+        let accounts = Array::from(&Reflect::get(&output, &JsValue::from("accounts"))?);
+        let account = if accounts.length() > 0 {
+            Some(WalletAccount::from(accounts.get(0)))
+        } else {
+            self.wallet.account_list().into_iter().next()
+        };
+        let account = account.ok_or_else(|| JsValue::from("Wallet returned no accounts"))?;
+        self.set_account(account);
+        Ok(())
+    }
+
+    async fn sign_transaction(&self, tx: &Transaction) -> Result<Transaction> {
+        let signed = self
+            .first_output(
+                WalletFeature::SignTransaction,
+                "signTransaction",
+                self.transaction_input(tx)?,
+                "signedTransaction",
+            )
+            .await?;
+        Transaction::from_bytes(&Uint8Array::new(&signed).to_vec())
+    }
+
+    async fn sign_and_send_transaction(&self, tx: &Transaction) -> Result<Signature> {
+        let signature = self
+            .first_output(
+                WalletFeature::SignAndSendTransaction,
+                "signAndSendTransaction",
+                self.transaction_input(tx)?,
+                "signature",
+            )
+            .await?;
+        signature_from_jsvalue(&signature)
+    }
+
+    async fn sign_all_transactions(&self, txs: &[Transaction]) -> Result<Vec<Transaction>> {
+        let inputs = Array::new();
+        for tx in txs {
+            inputs.push(&self.transaction_input(tx)?.into());
+        }
+        let outputs = self
+            .outputs(WalletFeature::SignTransaction, "signTransaction", &inputs)
+            .await?;
+        let mut signed = vec![];
+        for output in outputs.iter() {
+            let bytes = Reflect::get(&output, &JsValue::from("signedTransaction"))?;
+            signed.push(Transaction::from_bytes(&Uint8Array::new(&bytes).to_vec())?);
+        }
+        Ok(signed)
+    }
+
+    async fn sign_message(&self, message: &[u8]) -> Result<Signature> {
+        let input = Object::new();
+        Reflect::set(
+            &input,
+            &JsValue::from("account"),
+            &self.connected_account()?.into(),
+        )?;
+        Reflect::set(
+            &input,
+            &JsValue::from("message"),
+            &Uint8Array::from(message),
+        )?;
+        let signature = self
+            .first_output(
+                WalletFeature::SignMessage,
+                "signMessage",
+                input,
+                "signature",
+            )
+            .await?;
+        signature_from_jsvalue(&signature)
+    }
+}
@@ -0,0 +1,22 @@
+//!
+//! Shared fixture for the wasm integration tests.
+//!
+//! The tests run under Node.js through `wasm-bindgen-test-runner`, which
+//! `.cargo/config.toml` configures as the `wasm32-unknown-unknown` runner.
+//! The same file preloads `tests/common/setup.cjs` through `NODE_OPTIONS`,
+//! exposing the `@solana/web3.js` package installed by `npm install` as
+//! `globalThis.solanaWeb3`. See the README for the full setup.
+//!
+
+use solana_web3_sys::prelude::*;
+use wasm_bindgen::JsValue;
+
+/// Initialize the crate with the web3.js module preloaded by `setup.cjs`
+pub fn init() {
+    let global = js_sys::Reflect::get(&js_sys::global(), &JsValue::from("solanaWeb3")).unwrap();
+    assert!(
+        global.is_object(),
+        "globalThis.solanaWeb3 is not set; run `npm install` and the tests with `cargo test-wasm`"
+    );
+    init_solana_web3_sys(&global).unwrap();
+}
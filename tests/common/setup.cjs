@@ -0,0 +1,3 @@
+// Preloaded into Node.js by `NODE_OPTIONS` (see `.cargo/config.toml`) so the
+// wasm integration tests find web3.js at `globalThis.solanaWeb3`.
+globalThis.solanaWeb3 = require("@solana/web3.js");
@@ -0,0 +1,35 @@
+//!
+//! Decoding of thrown JavaScript values into [`Error`] variants.
+//!
+#![cfg(target_arch = "wasm32")]
+
+use js_sys::Reflect;
+use solana_web3_sys::error::Error;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+
+/// A wallet adapter `WalletError` wrapping the wallet's own error
+fn wallet_adapter_error(name: &str, code: i32) -> JsValue {
+    let inner = js_sys::Error::new("wallet error");
+    Reflect::set(&inner, &JsValue::from("code"), &JsValue::from(code)).unwrap();
+    let error = js_sys::Error::new("Unexpected error");
+    error.set_name(name);
+    Reflect::set(&error, &JsValue::from("error"), &inner).unwrap();
+    error.into()
+}
+
+#[wasm_bindgen_test]
+fn wrapped_user_rejection() {
+    let err = Error::from(wallet_adapter_error("WalletSignTransactionError", 4001));
+    assert!(matches!(err, Error::WalletRejected { .. }));
+    assert_eq!(err.js_error_info().unwrap().code.as_deref(), Some("4001"));
+}
+
+#[wasm_bindgen_test]
+fn wrapped_wallet_failure_is_not_rejection() {
+    let err = Error::from(wallet_adapter_error("WalletSignTransactionError", -32603));
+    assert!(!matches!(err, Error::WalletRejected { .. }));
+    let info = err.js_error_info().unwrap();
+    assert_eq!(info.name, "WalletSignTransactionError");
+    assert_eq!(info.code.as_deref(), Some("-32603"));
+}
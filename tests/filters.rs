@@ -0,0 +1,49 @@
+//!
+//! `getProgramAccounts` filter serialization tests.
+//!
+#![cfg(target_arch = "wasm32")]
+
+use solana_web3_sys::prelude::*;
+use wasm_bindgen_test::*;
+
+fn json(filters: &[RpcFilterType]) -> String {
+    let value = filters_to_jsvalue(filters).unwrap();
+    String::from(js_sys::JSON::stringify(&value).unwrap())
+}
+
+#[wasm_bindgen_test]
+fn filters_serialize_to_rpc_shape() {
+    let bytes = [1, 2, 3];
+    assert_eq!(
+        json(&[
+            RpcFilterType::DataSize(165),
+            RpcFilterType::Memcmp(Memcmp::new_base58_encoded(32, &bytes)),
+            RpcFilterType::Memcmp(Memcmp::new(
+                8,
+                MemcmpEncodedBytes::Base64("AQID".to_string())
+            )),
+            RpcFilterType::Memcmp(Memcmp::new_raw_bytes(0, bytes.to_vec())),
+            RpcFilterType::TokenAccountState,
+        ]),
+        concat!(
+            r#"[{"dataSize":165},"#,
+            r#"{"memcmp":{"offset":32,"bytes":"Ldp","encoding":"base58"}},"#,
+            r#"{"memcmp":{"offset":8,"bytes":"AQID","encoding":"base64"}},"#,
+            r#"{"memcmp":{"offset":0,"bytes":"AQID","encoding":"base64"}},"#,
+            r#""tokenAccountState"]"#
+        )
+    );
+}
+
+#[wasm_bindgen_test]
+fn invalid_filters_are_not_serialized() {
+    let oversized = vec![0; MAX_MEMCMP_DATA_SIZE + 1];
+    assert!(
+        filters_to_jsvalue(&[RpcFilterType::Memcmp(Memcmp::new_raw_bytes(0, oversized))]).is_err()
+    );
+    assert!(filters_to_jsvalue(&[RpcFilterType::Memcmp(Memcmp::new(
+        0,
+        MemcmpEncodedBytes::Base58("0OIl".to_string())
+    ))])
+    .is_err());
+}
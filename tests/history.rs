@@ -0,0 +1,95 @@
+//!
+//! `getTransaction` response decoding tests.
+//!
+#![cfg(target_arch = "wasm32")]
+
+use solana_sdk::instruction::{CompiledInstruction, InstructionError};
+use solana_sdk::transaction::TransactionError;
+use solana_web3_sys::prelude::*;
+use wasm_bindgen_test::*;
+
+fn meta(json: &str) -> TransactionStatusMeta {
+    let value = js_sys::JSON::parse(json).unwrap();
+    TransactionStatusMeta::try_from(&value).unwrap()
+}
+
+#[wasm_bindgen_test]
+fn meta_with_null_optional_fields_decodes_to_empty() {
+    let meta = meta(
+        r#"{
+            "err": null,
+            "fee": 5000,
+            "preBalances": [1000000, 1],
+            "postBalances": [995000, 1],
+            "innerInstructions": null,
+            "logMessages": null,
+            "preTokenBalances": null,
+            "postTokenBalances": null
+        }"#,
+    );
+    assert_eq!(meta.err, None);
+    assert_eq!(meta.fee, 5000);
+    assert_eq!(meta.pre_balances, vec![1_000_000, 1]);
+    assert_eq!(meta.post_balances, vec![995_000, 1]);
+    assert!(meta.inner_instructions.is_empty());
+    assert!(meta.log_messages.is_empty());
+    assert!(meta.pre_token_balances.is_empty());
+    assert!(meta.post_token_balances.is_empty());
+    assert_eq!(meta.compute_units_consumed, None);
+}
+
+#[wasm_bindgen_test]
+fn meta_with_populated_fields_decodes() {
+    let meta = meta(
+        r#"{
+            "err": {"InstructionError": [0, {"Custom": 6001}]},
+            "fee": 5000,
+            "preBalances": [],
+            "postBalances": [],
+            "innerInstructions": [
+                {"index": 0, "instructions": [{"programIdIndex": 2, "accounts": [0, 1], "data": "3Bxs4h24hBtQy9rw"}]}
+            ],
+            "logMessages": ["Program log: hello"],
+            "preTokenBalances": [],
+            "postTokenBalances": [],
+            "computeUnitsConsumed": 1200
+        }"#,
+    );
+    assert_eq!(
+        meta.err,
+        Some(TransactionError::InstructionError(0, InstructionError::Custom(6001)).into())
+    );
+    assert_eq!(meta.inner_instructions.len(), 1);
+    assert_eq!(
+        meta.inner_instructions[0].instructions,
+        vec![CompiledInstruction {
+            program_id_index: 2,
+            accounts: vec![0, 1],
+            data: bs58::decode("3Bxs4h24hBtQy9rw").into_vec().unwrap(),
+        }]
+    );
+    assert_eq!(meta.log_messages, vec!["Program log: hello".to_string()]);
+    assert_eq!(meta.compute_units_consumed, Some(1200));
+}
+
+#[wasm_bindgen_test]
+fn meta_with_unknown_error_keeps_raw_json() {
+    let meta = meta(
+        r#"{"err": "ProgramCacheHitMaxLimit", "fee": 0, "preBalances": [], "postBalances": []}"#,
+    );
+    assert_eq!(
+        meta.err,
+        Some(TransactionStatusError::Unknown(
+            r#""ProgramCacheHitMaxLimit""#.to_string()
+        ))
+    );
+}
+
+#[wasm_bindgen_test]
+fn meta_with_non_array_field_is_rejected() {
+    let value = js_sys::JSON::parse(
+        r#"{"err": null, "fee": 0, "preBalances": [], "postBalances": [], "logMessages": "oops"}"#,
+    )
+    .unwrap();
+    assert!(TransactionStatusMeta::try_from(&value).is_err());
+}
@@ -0,0 +1,84 @@
+//!
+//! `PublicKey` program-derived address tests, checking the web3.js derivations
+//! against `solana_sdk`.
+//!
+#![cfg(target_arch = "wasm32")]
+
+mod common;
+
+use common::init;
+use solana_sdk::pubkey::Pubkey;
+use solana_web3_sys::prelude::*;
+use wasm_bindgen_test::*;
+
+fn program_id() -> Pubkey {
+    Pubkey::new_from_array([7; 32])
+}
+
+#[wasm_bindgen_test]
+fn find_program_address_matches_rust() {
+    init();
+
+    let owner = Pubkey::new_from_array([9; 32]);
+    let seeds: [&[u8]; 3] = [b"vault", owner.as_ref(), &[1, 2, 3]];
+    let expected = Pubkey::find_program_address(&seeds, &program_id());
+    let actual = PublicKey::find_program_address(&seeds, &program_id()).unwrap();
+    assert_eq!(actual, expected);
+}
+
+#[wasm_bindgen_test]
+fn create_program_address_matches_rust() {
+    init();
+
+    let (address, bump) = Pubkey::find_program_address(&[b"config"], &program_id());
+    let seeds: [&[u8]; 2] = [b"config", &[bump]];
+    assert_eq!(
+        PublicKey::create_program_address(&seeds, &program_id()).unwrap(),
+        address
+    );
+    assert_eq!(
+        PublicKey::create_program_address(&seeds, &program_id()).unwrap(),
+        Pubkey::create_program_address(&seeds, &program_id()).unwrap()
+    );
+}
+
+#[wasm_bindgen_test]
+async fn create_with_seed_matches_rust() {
+    init();
+
+    let base = Pubkey::new_from_array([5; 32]);
+    let expected = Pubkey::create_with_seed(&base, "stake:0", &program_id()).unwrap();
+    let actual = PublicKey::create_with_seed(&base, "stake:0", &program_id())
+        .await
+        .unwrap();
+    assert_eq!(actual, expected);
+}
+
+#[wasm_bindgen_test]
+fn is_on_curve_matches_rust() {
+    init();
+
+    let (address, _) = Pubkey::find_program_address(&[b"off-curve"], &program_id());
+    assert!(!address.is_on_curve());
+    assert!(!PublicKey::is_on_curve(address.as_ref()).unwrap());
+
+    let system_program = solana_sdk::system_program::id();
+    assert_eq!(
+        PublicKey::is_on_curve(system_program.as_ref()).unwrap(),
+        system_program.is_on_curve()
+    );
+}
+
+#[wasm_bindgen_test]
+fn equals_and_to_base58_match_rust() {
+    init();
+
+    let (address, _) = Pubkey::find_program_address(&[b"equals"], &program_id());
+    let key = PublicKey::try_from(&address).unwrap();
+    let same = PublicKey::try_from(address.to_string().as_str()).unwrap();
+    let other = PublicKey::try_from(&program_id()).unwrap();
+
+    assert!(key.equals(&same));
+    assert!(!key.equals(&other));
+    assert_eq!(key.to_base58(), address.to_string());
+}
@@ -0,0 +1,68 @@
+//!
+//! `simulateTransaction` response decoding tests.
+//!
+#![cfg(target_arch = "wasm32")]
+
+use solana_sdk::account::Account;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::transaction::TransactionError;
+use solana_web3_sys::prelude::*;
+use wasm_bindgen_test::*;
+
+fn result(json: &str) -> SimulateTransactionResult {
+    let value = js_sys::JSON::parse(json).unwrap();
+    SimulateTransactionResult::try_from(&value).unwrap()
+}
+
+#[wasm_bindgen_test]
+fn result_with_populated_fields_decodes() {
+    let program_id = Pubkey::new_from_array([3; 32]);
+    let owner = Pubkey::new_from_array([4; 32]);
+    let result = result(&format!(
+        r#"{{
+            "err": {{"InstructionError": [1, {{"Custom": 42}}]}},
+            "logs": ["Program log: simulate"],
+            "unitsConsumed": 2400,
+            "returnData": {{"programId": "{program_id}", "data": ["AQID", "base64"]}},
+            "accounts": [
+                {{"lamports": 1000, "owner": "{owner}", "data": ["BAU=", "base64"], "executable": false, "rentEpoch": 18446744073709551615}},
+                null
+            ]
+        }}"#
+    ));
+    assert_eq!(
+        result.err,
+        Some(TransactionError::InstructionError(1, InstructionError::Custom(42)).into())
+    );
+    assert_eq!(result.logs, vec!["Program log: simulate".to_string()]);
+    assert_eq!(result.units_consumed, Some(2400));
+    let return_data = result.return_data.unwrap();
+    assert_eq!(return_data.program_id, program_id);
+    assert_eq!(return_data.data, vec![1, 2, 3]);
+    assert_eq!(
+        result.accounts,
+        vec![
+            Some(Account {
+                lamports: 1000,
+                data: vec![4, 5],
+                owner,
+                executable: false,
+                rent_epoch: u64::MAX,
+            }),
+            None,
+        ]
+    );
+}
+
+#[wasm_bindgen_test]
+fn result_with_null_optional_fields_decodes() {
+    let result = result(
+        r#"{"err": null, "logs": null, "unitsConsumed": null, "returnData": null, "accounts": null}"#,
+    );
+    assert_eq!(result.err, None);
+    assert!(result.logs.is_empty());
+    assert_eq!(result.units_consumed, None);
+    assert_eq!(result.return_data, None);
+    assert!(result.accounts.is_empty());
+}
@@ -0,0 +1,87 @@
+//!
+//! Subscription notification decoding tests.
+//!
+#![cfg(target_arch = "wasm32")]
+
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::signature::Signature;
+use solana_sdk::transaction::TransactionError;
+use solana_web3_sys::prelude::*;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+
+fn parse(json: &str) -> JsValue {
+    js_sys::JSON::parse(json).unwrap()
+}
+
+#[wasm_bindgen_test]
+fn logs_decode() {
+    let signature = Signature::from([1; 64]);
+    let logs = Logs::try_from(parse(&format!(
+        r#"{{
+            "signature": "{signature}",
+            "err": {{"InstructionError": [0, {{"Custom": 1}}]}},
+            "logs": ["Program log: hello", "Program log: world"]
+        }}"#
+    )))
+    .unwrap();
+    assert_eq!(logs.signature, signature);
+    assert_eq!(
+        logs.err,
+        Some(TransactionError::InstructionError(0, InstructionError::Custom(1)).into())
+    );
+    assert_eq!(
+        logs.logs,
+        vec![
+            "Program log: hello".to_string(),
+            "Program log: world".to_string()
+        ]
+    );
+
+    let logs = Logs::try_from(parse(&format!(
+        r#"{{"signature": "{signature}", "err": null, "logs": []}}"#
+    )))
+    .unwrap();
+    assert_eq!(logs.err, None);
+    assert!(logs.logs.is_empty());
+}
+
+#[wasm_bindgen_test]
+fn logs_with_invalid_signature_are_rejected() {
+    let value = parse(r#"{"signature": "not base58!", "err": null, "logs": []}"#);
+    assert!(Logs::try_from(value).is_err());
+}
+
+#[wasm_bindgen_test]
+fn slot_info_decodes() {
+    let slot_info = SlotInfo::try_from(parse(
+        r#"{"slot": 250000002, "parent": 250000001, "root": 249999970}"#,
+    ))
+    .unwrap();
+    assert_eq!(
+        slot_info,
+        SlotInfo {
+            slot: 250_000_002,
+            parent: 250_000_001,
+            root: 249_999_970,
+        }
+    );
+    assert!(SlotInfo::try_from(parse(r#"{"slot": 1, "parent": 0}"#)).is_err());
+}
+
+#[wasm_bindgen_test]
+fn signature_result_decodes() {
+    let result = SignatureResult::try_from(parse(r#"{"err": null}"#)).unwrap();
+    assert_eq!(result.err, None);
+
+    let result = SignatureResult::try_from(parse(r#"{"err": "AccountInUse"}"#)).unwrap();
+    assert_eq!(result.err, Some(TransactionError::AccountInUse.into()));
+
+    let result = SignatureResult::try_from(parse(r#"{"err": "ProgramCacheHitMaxLimit"}"#)).unwrap();
+    assert_eq!(
+        result.err,
+        Some(TransactionStatusError::Unknown(
+            r#""ProgramCacheHitMaxLimit""#.to_string()
+        ))
+    );
+}
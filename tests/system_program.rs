@@ -0,0 +1,131 @@
+//!
+//! `SystemProgram` instruction builder tests, checking each web3.js instruction
+//! against its `solana_program::system_instruction` equivalent.
+//!
+#![cfg(target_arch = "wasm32")]
+
+mod common;
+
+use common::init;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_instruction;
+use solana_web3_sys::prelude::*;
+use wasm_bindgen_test::*;
+
+fn key(byte: u8) -> Pubkey {
+    Pubkey::new_from_array([byte; 32])
+}
+
+/// Compare a web3.js instruction with the native one after converting the
+/// native instruction through `TryFrom<&Instruction>`
+fn assert_same(js: TransactionInstruction, native: Instruction) {
+    let converted = TransactionInstruction::try_from(&native).unwrap();
+    assert_eq!(js.data(), converted.data());
+    assert_eq!(
+        Instruction::try_from(&js).unwrap(),
+        Instruction::try_from(&converted).unwrap()
+    );
+    assert_eq!(Instruction::try_from(&js).unwrap(), native);
+}
+
+#[wasm_bindgen_test]
+fn transfer_matches_system_instruction() {
+    init();
+    assert_same(
+        SystemProgram::transfer(&key(1), &key(2), 1_000_000_007).unwrap(),
+        system_instruction::transfer(&key(1), &key(2), 1_000_000_007),
+    );
+}
+
+#[wasm_bindgen_test]
+fn create_account_matches_system_instruction() {
+    init();
+    assert_same(
+        SystemProgram::create_account(&key(1), &key(2), 2_039_280, 165, &key(3)).unwrap(),
+        system_instruction::create_account(&key(1), &key(2), 2_039_280, 165, &key(3)),
+    );
+}
+
+#[wasm_bindgen_test]
+fn create_account_with_seed_matches_system_instruction() {
+    init();
+    let base = key(4);
+    let address = Pubkey::create_with_seed(&base, "seed", &key(3)).unwrap();
+    assert_same(
+        SystemProgram::create_account_with_seed(
+            &key(1),
+            &address,
+            &base,
+            "seed",
+            2_039_280,
+            165,
+            &key(3),
+        )
+        .unwrap(),
+        system_instruction::create_account_with_seed(
+            &key(1),
+            &address,
+            &base,
+            "seed",
+            2_039_280,
+            165,
+            &key(3),
+        ),
+    );
+}
+
+#[wasm_bindgen_test]
+fn allocate_matches_system_instruction() {
+    init();
+    assert_same(
+        SystemProgram::allocate(&key(1), 1024).unwrap(),
+        system_instruction::allocate(&key(1), 1024),
+    );
+}
+
+#[wasm_bindgen_test]
+fn assign_matches_system_instruction() {
+    init();
+    assert_same(
+        SystemProgram::assign(&key(1), &key(3)).unwrap(),
+        system_instruction::assign(&key(1), &key(3)),
+    );
+}
+
+#[wasm_bindgen_test]
+fn nonce_initialize_matches_system_instruction() {
+    init();
+    let instructions = system_instruction::create_nonce_account(&key(1), &key(5), &key(6), 1);
+    assert_same(
+        SystemProgram::nonce_initialize(&key(5), &key(6)).unwrap(),
+        instructions[1].clone(),
+    );
+}
+
+#[wasm_bindgen_test]
+fn nonce_advance_matches_system_instruction() {
+    init();
+    assert_same(
+        SystemProgram::nonce_advance(&key(5), &key(6)).unwrap(),
+        system_instruction::advance_nonce_account(&key(5), &key(6)),
+    );
+}
+
+#[wasm_bindgen_test]
+fn nonce_withdraw_matches_system_instruction() {
+    init();
+    assert_same(
+        SystemProgram::nonce_withdraw(&key(5), &key(6), &key(2), 500).unwrap(),
+        system_instruction::withdraw_nonce_account(&key(5), &key(6), &key(2), 500),
+    );
+}
+
+#[wasm_bindgen_test]
+fn nonce_authorize_matches_system_instruction() {
+    init();
+    assert_same(
+        SystemProgram::nonce_authorize(&key(5), &key(6), &key(7)).unwrap(),
+        system_instruction::authorize_nonce_account(&key(5), &key(6), &key(7)),
+    );
+}
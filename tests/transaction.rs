@@ -0,0 +1,84 @@
+//!
+//! `Transaction` conversion tests.
+//!
+#![cfg(target_arch = "wasm32")]
+
+mod common;
+
+use common::init;
+use solana_sdk::hash::Hash;
+use solana_sdk::message::Message;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{keypair_from_seed, Signer};
+use solana_sdk::system_instruction;
+use solana_sdk::transaction::Transaction as SolanaTransaction;
+use solana_web3_sys::prelude::*;
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+fn transaction_round_trip_matches_bincode() {
+    init();
+
+    let payer = keypair_from_seed(&[1; 32]).unwrap();
+    let cosigner = keypair_from_seed(&[2; 32]).unwrap();
+    let to = Pubkey::new_from_array([3; 32]);
+    let instructions = [
+        system_instruction::transfer(&payer.pubkey(), &to, 42),
+        system_instruction::transfer(&cosigner.pubkey(), &to, 7),
+    ];
+    let mut tx = SolanaTransaction::new_with_payer(&instructions, Some(&payer.pubkey()));
+    tx.partial_sign(&[&payer], Hash::new_from_array([4; 32]));
+
+    let js_tx = Transaction::try_from(&tx).unwrap();
+    assert_eq!(js_tx.to_bytes().unwrap(), bincode::serialize(&tx).unwrap());
+
+    let native = SolanaTransaction::try_from(&js_tx).unwrap();
+    assert_eq!(native, tx);
+    assert_eq!(native.message.account_keys[0], payer.pubkey());
+    assert_eq!(native.message.recent_blockhash, tx.message.recent_blockhash);
+    assert_eq!(native.signatures, tx.signatures);
+}
+
+#[wasm_bindgen_test]
+fn signing_matches_solana_sdk() {
+    init();
+
+    let payer = keypair_from_seed(&[1; 32]).unwrap();
+    let cosigner = keypair_from_seed(&[2; 32]).unwrap();
+    let js_payer = Keypair::from_secret_key(&payer.to_bytes()).unwrap();
+    let js_cosigner = Keypair::from_secret_key(&cosigner.to_bytes()).unwrap();
+    assert_eq!(js_payer.pubkey().unwrap(), payer.pubkey());
+    assert_eq!(js_cosigner.pubkey().unwrap(), cosigner.pubkey());
+
+    let to = Pubkey::new_from_array([3; 32]);
+    let instructions = [
+        system_instruction::transfer(&payer.pubkey(), &to, 42),
+        system_instruction::transfer(&cosigner.pubkey(), &to, 7),
+    ];
+    let blockhash = Hash::new_from_array([4; 32]);
+    let unsigned = SolanaTransaction::new_unsigned(Message::new_with_blockhash(
+        &instructions,
+        Some(&payer.pubkey()),
+        &blockhash,
+    ));
+    let mut expected = unsigned.clone();
+    expected.partial_sign(&[&payer], blockhash);
+    expected.partial_sign(&[&cosigner], blockhash);
+
+    let js_tx = Transaction::try_from(&unsigned).unwrap();
+    js_tx.partial_sign(&[&js_payer]).unwrap();
+    let cosignature = cosigner.sign_message(&unsigned.message_data());
+    js_tx
+        .add_signature(&cosigner.pubkey(), &cosignature)
+        .unwrap();
+    let signed = SolanaTransaction::try_from(&js_tx).unwrap();
+    assert_eq!(signed.signatures, expected.signatures);
+    assert_eq!(signed, expected);
+
+    let js_tx = Transaction::try_from(&unsigned).unwrap();
+    js_tx.sign(&[&js_payer, &js_cosigner]).unwrap();
+    assert_eq!(
+        SolanaTransaction::try_from(&js_tx).unwrap().signatures,
+        expected.signatures
+    );
+}
@@ -0,0 +1,40 @@
+//!
+//! `VersionedTransaction` conversion tests.
+//!
+#![cfg(target_arch = "wasm32")]
+
+mod common;
+
+use common::init;
+use solana_sdk::hash::Hash;
+use solana_sdk::message::{v0, VersionedMessage};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{keypair_from_seed, Signer};
+use solana_sdk::system_instruction;
+use solana_sdk::transaction::VersionedTransaction as SolanaVersionedTransaction;
+use solana_web3_sys::prelude::*;
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+fn versioned_transaction_round_trip_matches_bincode() {
+    init();
+
+    let payer = keypair_from_seed(&[1; 32]).unwrap();
+    let to = Pubkey::new_from_array([3; 32]);
+    let message = v0::Message::try_compile(
+        &payer.pubkey(),
+        &[system_instruction::transfer(&payer.pubkey(), &to, 42)],
+        &[],
+        Hash::new_from_array([4; 32]),
+    )
+    .unwrap();
+    let tx = SolanaVersionedTransaction::try_new(VersionedMessage::V0(message), &[&payer]).unwrap();
+
+    let js_tx = VersionedTransaction::try_from(&tx).unwrap();
+    assert_eq!(js_tx.version().as_f64(), Some(0.0));
+    assert_eq!(js_tx.serialize(), bincode::serialize(&tx).unwrap());
+
+    let native = SolanaVersionedTransaction::try_from(&js_tx).unwrap();
+    assert_eq!(native, tx);
+    assert!(native.verify_with_results().into_iter().all(|ok| ok));
+}